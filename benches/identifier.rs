@@ -0,0 +1,56 @@
+use aws_arn::{IdentifierLike, ResourceIdentifier, ResourceName};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+const RESOURCES: &[&str] = &[
+    "my-bucket",
+    "user/division_abc/subdivision_xyz/Bob",
+    "function:my-function:3",
+    "photos/2024/München/Straße.png",
+];
+
+fn resource_identifier_is_valid(c: &mut Criterion) {
+    let _ = c.bench_function("ResourceIdentifier::is_valid", |b| {
+        b.iter(|| {
+            for resource in RESOURCES {
+                let _ = black_box(ResourceIdentifier::is_valid(black_box(resource)));
+            }
+        })
+    });
+}
+
+fn resource_name_from_str(c: &mut Criterion) {
+    let _ = c.bench_function("ResourceName::from_str", |b| {
+        b.iter(|| {
+            ResourceName::from_str(black_box(
+                "arn:aws:lambda:us-east-2:123456789012:function:my-function:3",
+            ))
+        })
+    });
+}
+
+// Compare an identifier without variables, which skips the regex replacement entirely, to
+// one with a single variable of the same length.
+fn resource_identifier_replace_variables(c: &mut Criterion) {
+    let context: HashMap<String, &str> = vec![("name".to_string(), "Bob")].into_iter().collect();
+    let mut group = c.benchmark_group("ResourceIdentifier::replace_variables");
+    for (label, resource) in &[
+        ("without variables", "user/division_abc/Bob"),
+        ("with variables", "user/division_abc/${name}"),
+    ] {
+        let id = ResourceIdentifier::from_str(resource).unwrap();
+        let _ = group.bench_function(*label, |b| {
+            b.iter(|| black_box(&id).replace_variables(black_box(&context)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    resource_identifier_is_valid,
+    resource_name_from_str,
+    resource_identifier_replace_variables
+);
+criterion_main!(benches);
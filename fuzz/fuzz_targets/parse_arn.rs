@@ -0,0 +1,26 @@
+//! Feeds arbitrary input to `ResourceName::from_str`, checking that it never panics and that
+//! any ARN it accepts is displayed as a string that parses back to the same value.
+//!
+//! Run with `cargo fuzz run parse_arn`; the corpus is seeded from `tests/data/examples.txt`.
+
+#![no_main]
+
+use aws_arn::ResourceName;
+use libfuzzer_sys::fuzz_target;
+use std::str::FromStr;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else {
+        return;
+    };
+    if let Ok(arn) = ResourceName::from_str(s) {
+        let displayed = arn.to_string();
+        assert_eq!(
+            ResourceName::from_str(&displayed),
+            Ok(arn),
+            "{:?} was displayed as {:?}",
+            s,
+            displayed
+        );
+    }
+});
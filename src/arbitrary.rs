@@ -0,0 +1,67 @@
+//! Provides `proptest` strategies for generating valid `ResourceName` values.
+
+use crate::{
+    AccountId, AccountIdentifier, Identifier, IdentifierLike, Partition, Region,
+    ResourceIdentifier, ResourceName, Service,
+};
+use proptest::prelude::*;
+use proptest::sample::select;
+use strum::VariantArray;
+
+impl Arbitrary for ResourceName {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Generate values with any known partition and service, a region in that partition
+    /// where the service allows or requires one, an optional account that is either an account
+    /// ID or a service identifier, and a legal resource identifier. Every value generated
+    /// passes `ResourceName::validate` and will display as a string that parses back to an
+    /// equal value.
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (select(Partition::VARIANTS), select(Service::VARIANTS))
+            .prop_flat_map(|(partition, service)| {
+                (
+                    Just(partition.clone()),
+                    Just(service.clone()),
+                    region(&partition, &service),
+                    proptest::option::of(account_identifier()),
+                    resource_identifier(),
+                )
+            })
+            .prop_map(
+                |(partition, service, region, account_id, resource)| ResourceName {
+                    partition,
+                    service,
+                    region,
+                    account_id,
+                    resource,
+                    source: None,
+                },
+            )
+            .boxed()
+    }
+}
+
+// Global services have no region, regional services always have one, and others may have
+// either; any region is one of those in the partition.
+fn region(partition: &Partition, service: &Service) -> BoxedStrategy<Option<Region>> {
+    let regions = select(partition.regions());
+    if service.is_global() {
+        Just(None).boxed()
+    } else if service.is_regional() {
+        regions.prop_map(Some).boxed()
+    } else {
+        proptest::option::of(regions).boxed()
+    }
+}
+
+fn account_identifier() -> impl Strategy<Value = AccountIdentifier> {
+    prop_oneof![
+        "[0-9]{12}".prop_map(|s| AccountIdentifier::Account(AccountId::new_unchecked(&s))),
+        "[a-z][a-z-]{0,15}".prop_map(|s| AccountIdentifier::Service(Identifier::new_unchecked(&s))),
+    ]
+}
+
+fn resource_identifier() -> impl Strategy<Value = ResourceIdentifier> {
+    r"[[[:ascii:]]&&[[:^cntrl:]]]+".prop_map(|s| ResourceIdentifier::new_unchecked(&s))
+}
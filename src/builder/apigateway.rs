@@ -0,0 +1,69 @@
+//! Higher-level utilities to build ARNs for Amazon API Gateway.
+//!
+//! API Gateway uses two ARN formats: management ARNs, in the `apigateway` namespace, name REST
+//! and HTTP APIs as resources and have no account and a resource path beginning with '/';
+//! invoke ARNs, in the `execute-api` namespace, name the methods that may be called on a
+//! deployed API.
+//!
+//! For more information, check out the [AWS documentation](https://docs.aws.amazon.com/apigateway/latest/developerguide/arn-format-reference.html)
+
+use crate::{
+    AccountId, Identifier, IdentifierLike, Partition, Region, ResourceIdentifier, ResourceName,
+    Service::{ApiGateway, ApiGatewayExecuteApi},
+};
+
+///
+/// `arn:${Partition}:apigateway:${Region}::/restapis/${ApiId}`
+///
+pub fn rest_api(partition: Partition, region: Region, api_id: Identifier) -> ResourceName {
+    management(partition, region, "restapis", api_id)
+}
+
+///
+/// `arn:${Partition}:apigateway:${Region}::/apis/${ApiId}`
+///
+pub fn http_api(partition: Partition, region: Region, api_id: Identifier) -> ResourceName {
+    management(partition, region, "apis", api_id)
+}
+
+///
+/// `arn:${Partition}:execute-api:${Region}:${Account}:${ApiId}/${Stage}/${Method}/${ResourcePath}`
+///
+pub fn invoke(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    api_id: Identifier,
+    stage: Identifier,
+    method: Identifier,
+    resource_path: ResourceIdentifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(ApiGatewayExecuteApi)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_path(&[
+            api_id.into(),
+            stage.into(),
+            method.into(),
+            resource_path,
+        ]))
+        .build()
+}
+
+fn management(
+    partition: Partition,
+    region: Region,
+    resource_type: &str,
+    api_id: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(ApiGateway)
+        .in_partition(partition)
+        .in_region(region)
+        .is(ResourceIdentifier::new_unchecked(&format!(
+            "/{resource_type}/{api_id}"
+        )))
+        .build()
+}
@@ -0,0 +1,55 @@
+//! Higher-level utilities to build ARNs for Amazon Athena.
+//!
+//! Query executions do not have ARNs of their own; access to a query execution is
+//! controlled through the ARN of the workgroup in which the query runs.
+//!
+//! For more information, check out the [AWS documentation](https://docs.aws.amazon.com/service-authorization/latest/reference/list_amazonathena.html#amazonathena-resources-for-iam-policies)
+
+use crate::{
+    AccountId, Identifier, IdentifierLike, Partition, Region, ResourceIdentifier, ResourceName,
+    Service::Athena,
+};
+
+///
+/// `arn:${Partition}:athena:${Region}:${Account}:workgroup/${WorkGroupName}`
+///
+/// This is also the ARN used to authorize query executions run within the workgroup.
+///
+pub fn workgroup(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    workgroup_name: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(Athena)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("workgroup"),
+            workgroup_name,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:athena:${Region}:${Account}:datacatalog/${DataCatalogName}`
+///
+pub fn data_catalog(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    catalog_name: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(Athena)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("datacatalog"),
+            catalog_name,
+        ]))
+        .build()
+}
@@ -0,0 +1,57 @@
+//! Higher-level utilities to build ARNs for AWS CloudFormation.
+//!
+//! Stack and change set ARNs end with a unique ID, a GUID assigned by CloudFormation when the
+//! resource is created, so that resources re-created with the same name have distinct ARNs.
+//!
+//! For more information, check out the [AWS documentation](https://docs.aws.amazon.com/service-authorization/latest/reference/list_awscloudformation.html#awscloudformation-resources-for-iam-policies)
+
+use crate::{
+    AccountId, Identifier, IdentifierLike, Partition, Region, ResourceIdentifier, ResourceName,
+    Service::CloudFormation,
+};
+
+///
+/// `arn:${Partition}:cloudformation:${Region}:${Account}:stack/${StackName}/${Id}`
+///
+pub fn stack(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    stack_name: Identifier,
+    stack_id: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(CloudFormation)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("stack"),
+            stack_name,
+            stack_id,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:cloudformation:${Region}:${Account}:changeSet/${ChangeSetName}/${Id}`
+///
+pub fn change_set(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    change_set_name: Identifier,
+    change_set_id: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(CloudFormation)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("changeSet"),
+            change_set_name,
+            change_set_id,
+        ]))
+        .build()
+}
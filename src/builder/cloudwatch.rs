@@ -0,0 +1,32 @@
+//! Higher-level utilities to build ARNs for Amazon CloudWatch.
+//!
+//! For more information, check out the [AWS documentation](https://docs.aws.amazon.com/service-authorization/latest/reference/list_amazoncloudwatch.html#amazoncloudwatch-resources-for-iam-policies)
+
+use crate::{
+    AccountId, IdentifierLike, Partition, Region, ResourceIdentifier, ResourceName,
+    Service::CloudWatch,
+};
+
+///
+/// `arn:${Partition}:cloudwatch:${Region}:${Account}:alarm:${AlarmName}`
+///
+/// Note that alarm names may themselves contain ':' characters, so the name is taken as a
+/// `ResourceIdentifier` rather than an `Identifier`.
+///
+pub fn alarm(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    alarm_name: ResourceIdentifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(CloudWatch)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_qualified(&[
+            ResourceIdentifier::new_unchecked("alarm"),
+            alarm_name,
+        ]))
+        .build()
+}
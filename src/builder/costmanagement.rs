@@ -0,0 +1,82 @@
+//! Higher-level utilities to build ARNs for the AWS cost management services; AWS Budgets,
+//! AWS Cost Explorer, and Savings Plans.
+//!
+//! All of these services are global, and so the ARNs they produce omit the region component.
+//!
+//! For more information, check out the AWS documentation for
+//! [AWS Budgets](https://docs.aws.amazon.com/service-authorization/latest/reference/list_awsbudgetservice.html#awsbudgetservice-resources-for-iam-policies),
+//! [AWS Cost Explorer](https://docs.aws.amazon.com/service-authorization/latest/reference/list_awscostexplorerservice.html#awscostexplorerservice-resources-for-iam-policies), and
+//! [Savings Plans](https://docs.aws.amazon.com/service-authorization/latest/reference/list_awssavingsplans.html#awssavingsplans-resources-for-iam-policies).
+
+use crate::{
+    AccountId, Identifier, IdentifierLike, Partition, ResourceIdentifier, ResourceName,
+    Service::{Budgets, CostExplorer, SavingsPlans},
+};
+
+///
+/// `arn:${Partition}:savingsplans::${Account}:savingsplan/${ResourceId}`
+///
+pub fn savings_plan(partition: Partition, account: AccountId, plan_id: Identifier) -> ResourceName {
+    ResourceName::builder()
+        .service(SavingsPlans)
+        .in_partition(partition)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("savingsplan"),
+            plan_id,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:budgets::${Account}:budget/${BudgetName}`
+///
+pub fn budget(partition: Partition, account: AccountId, budget_name: Identifier) -> ResourceName {
+    ResourceName::builder()
+        .service(Budgets)
+        .in_partition(partition)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("budget"),
+            budget_name,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:ce::${Account}:costcategory/${CostCategoryId}`
+///
+pub fn cost_category(
+    partition: Partition,
+    account: AccountId,
+    cost_category_id: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(CostExplorer)
+        .in_partition(partition)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("costcategory"),
+            cost_category_id,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:ce::${Account}:anomalymonitor/${Identifier}`
+///
+pub fn anomaly_monitor(
+    partition: Partition,
+    account: AccountId,
+    monitor_id: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(CostExplorer)
+        .in_partition(partition)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("anomalymonitor"),
+            monitor_id,
+        ]))
+        .build()
+}
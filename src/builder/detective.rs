@@ -0,0 +1,31 @@
+//! Higher-level utilities to build ARNs for Amazon Detective.
+//!
+//! For more information, check out the [AWS documentation](https://docs.aws.amazon.com/service-authorization/latest/reference/list_amazondetective.html#amazondetective-resources-for-iam-policies)
+
+use crate::{
+    AccountId, Identifier, IdentifierLike, Partition, Region, ResourceIdentifier, ResourceName,
+    Service::Detective,
+};
+
+///
+/// `arn:${Partition}:detective:${Region}:${Account}:graph:${ResourceId}`
+///
+/// Note that, unlike the Amazon GuardDuty detector ARN, the graph ARN uses a ':' separator.
+///
+pub fn graph(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    graph_id: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(Detective)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_qualified_id(&[
+            Identifier::new_unchecked("graph"),
+            graph_id,
+        ]))
+        .build()
+}
@@ -0,0 +1,83 @@
+//! Higher-level utilities to build ARNs for Amazon EC2 (Elastic Compute Cloud).
+//!
+//! Note that Amazon Machine Images (AMIs) and EBS snapshots may be shared between accounts,
+//! and so their ARNs omit the account component.
+//!
+//! For more information, check out the [AWS documentation](https://docs.aws.amazon.com/service-authorization/latest/reference/list_amazonec2.html#amazonec2-resources-for-iam-policies)
+
+use crate::{
+    AccountId, Identifier, IdentifierLike, Partition, Region, ResourceIdentifier, ResourceName,
+    Service::Ec2,
+};
+
+///
+/// `arn:${Partition}:ec2:${Region}::image/${ImageId}`
+///
+pub fn image(partition: Partition, region: Region, image_id: Identifier) -> ResourceName {
+    ResourceName::builder()
+        .service(Ec2)
+        .in_partition(partition)
+        .in_region(region)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("image"),
+            image_id,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:ec2:${Region}::snapshot/${SnapshotId}`
+///
+pub fn snapshot(partition: Partition, region: Region, snapshot_id: Identifier) -> ResourceName {
+    ResourceName::builder()
+        .service(Ec2)
+        .in_partition(partition)
+        .in_region(region)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("snapshot"),
+            snapshot_id,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:ec2:${Region}:${Account}:launch-template/${LaunchTemplateId}`
+///
+pub fn launch_template(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    launch_template_id: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(Ec2)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("launch-template"),
+            launch_template_id,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:ec2:${Region}:${Account}:key-pair/${KeyPairName}`
+///
+pub fn key_pair(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    key_pair_name: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(Ec2)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("key-pair"),
+            key_pair_name,
+        ]))
+        .build()
+}
@@ -0,0 +1,35 @@
+//! Higher-level utilities to build ARNs for Amazon Elastic Container Registry.
+//!
+//! Note that repositories are the only ECR resource type with an ARN; individual images are
+//! identified by tag or digest within a repository, and IAM policies grant access to images
+//! through the repository ARN.
+//!
+//! For more information, check out the [AWS documentation](https://docs.aws.amazon.com/service-authorization/latest/reference/list_amazonelasticcontainerregistry.html#amazonelasticcontainerregistry-resources-for-iam-policies)
+
+use crate::{
+    AccountId, IdentifierLike, Partition, Region, ResourceIdentifier, ResourceName,
+    Service::Ec2ContainerRegistry,
+};
+
+///
+/// `arn:${Partition}:ecr:${Region}:${Account}:repository/${RepositoryName}`
+///
+/// The repository name may include a namespace, as in `team/service`, which is kept as-is.
+///
+pub fn repository(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    repository_name: ResourceIdentifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(Ec2ContainerRegistry)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_path(&[
+            ResourceIdentifier::new_unchecked("repository"),
+            repository_name,
+        ]))
+        .build()
+}
@@ -0,0 +1,103 @@
+//! Higher-level utilities to build ARNs for Amazon Elastic Container Service.
+//!
+//! Note that the functions below produce the long ARN format, in which the cluster name is
+//! included in service and task ARNs, as is the default for all new accounts.
+//!
+//! For more information, check out the [AWS documentation](https://docs.aws.amazon.com/service-authorization/latest/reference/list_amazonelasticcontainerservice.html#amazonelasticcontainerservice-resources-for-iam-policies)
+
+use crate::{
+    AccountId, Identifier, IdentifierLike, Partition, Region, ResourceIdentifier, ResourceName,
+    Service::Ec2ContainerService,
+};
+
+///
+/// `arn:${Partition}:ecs:${Region}:${Account}:cluster/${ClusterName}`
+///
+pub fn cluster(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    cluster_name: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(Ec2ContainerService)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("cluster"),
+            cluster_name,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:ecs:${Region}:${Account}:service/${ClusterName}/${ServiceName}`
+///
+pub fn service(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    cluster_name: Identifier,
+    service_name: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(Ec2ContainerService)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("service"),
+            cluster_name,
+            service_name,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:ecs:${Region}:${Account}:task/${ClusterName}/${TaskId}`
+///
+pub fn task(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    cluster_name: Identifier,
+    task_id: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(Ec2ContainerService)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("task"),
+            cluster_name,
+            task_id,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:ecs:${Region}:${Account}:task-definition/${TaskDefinitionFamilyName}:${TaskDefinitionRevisionNumber}`
+///
+pub fn task_definition(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    family: Identifier,
+    revision: u32,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(Ec2ContainerService)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_qualified(&[
+            ResourceIdentifier::from_id_path(&[
+                Identifier::new_unchecked("task-definition"),
+                family,
+            ]),
+            ResourceIdentifier::new_unchecked(&revision.to_string()),
+        ]))
+        .build()
+}
@@ -0,0 +1,31 @@
+//! Higher-level utilities to build ARNs for Amazon GuardDuty.
+//!
+//! For more information, check out the [AWS documentation](https://docs.aws.amazon.com/service-authorization/latest/reference/list_amazonguardduty.html#amazonguardduty-resources-for-iam-policies)
+
+use crate::{
+    AccountId, Identifier, IdentifierLike, Partition, Region, ResourceIdentifier, ResourceName,
+    Service::GuardDuty,
+};
+
+///
+/// `arn:${Partition}:guardduty:${Region}:${Account}:detector/${DetectorId}`
+///
+/// Note that, unlike the Amazon Detective graph ARN, the detector ARN uses a '/' separator.
+///
+pub fn detector(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    detector_id: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(GuardDuty)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("detector"),
+            detector_id,
+        ]))
+        .build()
+}
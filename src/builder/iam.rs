@@ -3,6 +3,11 @@
 //! For more information, see the [AWS documentation](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_identityandaccessmanagement.html#identityandaccessmanagement-resources-for-iam-policies).
 //! See [here](https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_identifiers.html#identifiers-arns) for
 //! documentation on the AWS ARN for root AWS accounts.
+//!
+//! IAM is a global service, and its ARNs have no region. When building IAM ARNs directly with
+//! [`ResourceName::builder`], finish with
+//! [`try_build`](crate::builder::ResourceNameBuilder::try_build) rather than `build` so that a
+//! region given by mistake is rejected.
 
 use crate::{
     AccountId, Identifier, IdentifierLike, Partition, ResourceIdentifier, ResourceName,
@@ -79,3 +84,82 @@ pub fn policy(partition: Partition, account: AccountId, policy_name: Identifier)
         ]))
         .build()
 }
+
+///
+/// `arn:${Partition}:iam::${Account}:instance-profile/${InstanceProfileNameWithPath}`
+///
+pub fn instance_profile(
+    partition: Partition,
+    account: AccountId,
+    instance_profile_name: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(IdentityAccessManagement)
+        .in_partition(partition)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("instance-profile"),
+            instance_profile_name,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:saml-provider/${SamlProviderName}`
+///
+pub fn saml_provider(
+    partition: Partition,
+    account: AccountId,
+    provider_name: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(IdentityAccessManagement)
+        .in_partition(partition)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("saml-provider"),
+            provider_name,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:oidc-provider/${OidcProviderName}`
+///
+/// Note that the provider name is the provider URL without the `https://` scheme, which may
+/// include a path, so it is taken as a `ResourceIdentifier`.
+///
+pub fn oidc_provider(
+    partition: Partition,
+    account: AccountId,
+    provider_url: ResourceIdentifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(IdentityAccessManagement)
+        .in_partition(partition)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_path(&[
+            ResourceIdentifier::new_unchecked("oidc-provider"),
+            provider_url,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:iam::${Account}:server-certificate/${CertificateNameWithPath}`
+///
+pub fn server_certificate(
+    partition: Partition,
+    account: AccountId,
+    certificate_name: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(IdentityAccessManagement)
+        .in_partition(partition)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("server-certificate"),
+            certificate_name,
+        ]))
+        .build()
+}
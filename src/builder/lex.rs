@@ -0,0 +1,57 @@
+//! Higher-level utilities to build ARNs for Amazon Lex V2.
+//!
+//! Note that Amazon Lex V1 and V2 both use the `lex` ARN namespace, unlike their API
+//! endpoints which are `lex-models`/`lex-runtime` and `lexv2-models`/`lexv2-runtime`
+//! respectively. V1 bots are addressed by name (`bot:${BotName}`) whereas V2 bots are
+//! addressed by their generated ID as produced by the functions below.
+//!
+//! For more information, check out the [AWS documentation](https://docs.aws.amazon.com/service-authorization/latest/reference/list_amazonlexv2.html#amazonlexv2-resources-for-iam-policies)
+
+use crate::{
+    AccountId, Identifier, IdentifierLike, Partition, Region, ResourceIdentifier, ResourceName,
+    Service::Lex,
+};
+
+///
+/// `arn:${Partition}:lex:${Region}:${Account}:bot/${BotId}`
+///
+pub fn bot(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    bot_id: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(Lex)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("bot"),
+            bot_id,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:lex:${Region}:${Account}:bot-alias/${BotId}/${BotAliasId}`
+///
+pub fn bot_alias(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    bot_id: Identifier,
+    alias_id: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(Lex)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("bot-alias"),
+            bot_id,
+            alias_id,
+        ]))
+        .build()
+}
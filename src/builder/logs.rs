@@ -0,0 +1,57 @@
+//! Higher-level utilities to build ARNs for Amazon CloudWatch Logs.
+//!
+//! For more information, check out the [AWS documentation](https://docs.aws.amazon.com/service-authorization/latest/reference/list_amazoncloudwatchlogs.html#amazoncloudwatchlogs-resources-for-iam-policies)
+
+use crate::{
+    AccountId, IdentifierLike, Partition, Region, ResourceIdentifier, ResourceName,
+    Service::CloudWatchLogs,
+};
+
+///
+/// `arn:${Partition}:logs:${Region}:${Account}:log-group:${LogGroupName}:*`
+///
+/// Note that log group names commonly contain '/' characters, for example
+/// `/aws/lambda/my-function`, so the name is taken as a `ResourceIdentifier`.
+///
+pub fn log_group(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    log_group_name: ResourceIdentifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(CloudWatchLogs)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_qualified(&[
+            ResourceIdentifier::new_unchecked("log-group"),
+            log_group_name,
+            ResourceIdentifier::any(),
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:logs:${Region}:${Account}:log-group:${LogGroupName}:log-stream:${LogStreamName}`
+///
+pub fn log_stream(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    log_group_name: ResourceIdentifier,
+    log_stream_name: ResourceIdentifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(CloudWatchLogs)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_qualified(&[
+            ResourceIdentifier::new_unchecked("log-group"),
+            log_group_name,
+            ResourceIdentifier::new_unchecked("log-stream"),
+            log_stream_name,
+        ]))
+        .build()
+}
@@ -17,8 +17,10 @@
 //! constructed from an existing bucket ResourceName, additional `{noun}_from(other,...)` functions will
 //! be provided.
 //!
-//! Note that the final `build()` function will call `validate()`, and so it is possible to call
-//! intermediate functions with bad data which is only caught at build time.
+//! The final `build()` function is unchecked: it does not validate the components it is given,
+//! so misuse such as a region given for a global service like IAM is **not** caught by it. Use
+//! [`try_build()`](ResourceNameBuilder::try_build) instead, which calls
+//! [`ResourceName::validate()`], to have that misuse caught at build time.
 //!
 //! # Example
 //!
@@ -49,13 +51,36 @@
 //!     .owned_by(AccountId::from_str("123456789012").unwrap())
 //!     .build();
 //! ```
+//!
+//! The AWS SDK crates take and return ARNs as `String` values; a built `ResourceName` converts
+//! to the canonical string form using `String::from`, and `ResourceName::try_from_sdk` parses
+//! values returned from the SDK.
+//!
+//! ```rust
+//! use aws_arn::builder::lambda;
+//! use aws_arn::{AccountId, Identifier, IdentifierLike, Partition, Region, ResourceName};
+//!
+//! let arn = lambda::function(
+//!     Partition::Aws,
+//!     Region::UsEast2,
+//!     AccountId::new_unchecked("123456789012"),
+//!     Identifier::new_unchecked("my-function"),
+//! );
+//! let function_arn: String = arn.clone().into();
+//! assert_eq!(
+//!     function_arn,
+//!     "arn:aws:lambda:us-east-2:123456789012:function:my-function"
+//! );
+//! assert_eq!(ResourceName::try_from_sdk(&function_arn).unwrap(), arn);
+//! ```
 
 pub use crate::ResourceNameBuilder;
 use crate::{
-    resource_name_builder::{IsUnset, SetInAccount, SetInRegion, SetResource, State},
-    types::AccountIdentifier,
-    Identifier, IdentifierLike, Region, ResourceIdentifier,
+    resource_name_builder::{IsComplete, IsUnset, SetInAccount, SetInRegion, SetResource, State},
+    types::{AccountIdentifier, PART_SEPARATOR, PATH_SEPARATOR},
+    ArnError, ArnResult, Identifier, IdentifierLike, Region, ResourceIdentifier, ResourceName,
 };
+use std::iter::FromIterator;
 
 impl<S: State> ResourceNameBuilder<S> {
     /// Specifies the AWS region where the resource described by the ARN being built
@@ -107,10 +132,36 @@ impl<S: State> ResourceNameBuilder<S> {
     }
 }
 
+impl<S: IsComplete> ResourceNameBuilder<S> {
+    /// Finish building the `ResourceName`, returning an error if it fails
+    /// `ResourceName::validate()`.
+    pub fn try_build(self) -> ArnResult<ResourceName> {
+        let arn = self.build();
+        arn.validate()?;
+        Ok(arn)
+    }
+}
+
 /// Builder type for a `ResourceIdentifier`.
 ///
 /// The methods `build_resource_path` and `build_qualified_id` are used to construct identifiers
-/// with either the '/' or ':' separator between the collected components.
+/// with either the '/' or ':' separator between the collected components, and `build_mixed` to
+/// construct identifiers that use both.
+///
+/// Components may be added either with the methods that take `&mut self`, such as
+/// `resource_name`, or with the corresponding `with_` methods, such as `with_resource_name`,
+/// that take and return the builder by value and so may be chained from a constructor into a
+/// variable or a `build_` method.
+///
+/// ```rust
+/// use aws_arn::builder::ResourceBuilder;
+/// use aws_arn::{Identifier, IdentifierLike};
+///
+/// let builder = ResourceBuilder::typed(Identifier::new_unchecked("layer"))
+///     .with_resource_name(Identifier::new_unchecked("my-layer"))
+///     .with_version(3);
+/// assert_eq!(builder.build_qualified_id().to_string(), "layer:my-layer:3");
+/// ```
 ///
 #[derive(Clone, Debug, Default)]
 pub struct ResourceBuilder {
@@ -131,8 +182,35 @@ impl From<Identifier> for ResourceBuilder {
     }
 }
 
+impl FromIterator<ResourceIdentifier> for ResourceBuilder {
+    fn from_iter<T: IntoIterator<Item = ResourceIdentifier>>(iter: T) -> Self {
+        Self {
+            resource: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl FromIterator<Identifier> for ResourceBuilder {
+    fn from_iter<T: IntoIterator<Item = Identifier>>(iter: T) -> Self {
+        iter.into_iter().map(ResourceIdentifier::from).collect()
+    }
+}
+
+impl Extend<ResourceIdentifier> for ResourceBuilder {
+    fn extend<T: IntoIterator<Item = ResourceIdentifier>>(&mut self, iter: T) {
+        self.resource.extend(iter)
+    }
+}
+
 impl ResourceBuilder {
+    /// Construct a new resource builder containing all of the provided identifiers, in order.
+    #[must_use]
+    pub fn from_ids(ids: &[Identifier]) -> Self {
+        ids.iter().cloned().collect()
+    }
+
     /// Construct a new resource builder containing only the provided identifier.
+    #[must_use]
     pub fn named(id: Identifier) -> Self {
         Self {
             resource: vec![id.into()],
@@ -140,12 +218,54 @@ impl ResourceBuilder {
     }
 
     /// Construct a new resource builder containing only the provided identifier.
+    #[must_use]
     pub fn typed(id: Identifier) -> Self {
         Self {
             resource: vec![id.into()],
         }
     }
 
+    /// Construct a new resource builder by splitting a qualified resource, such as
+    /// `layer:my-layer:3`, on the ':' separator; this is the inverse of `build_qualified_id`.
+    /// Returns an error if any component is not a valid `ResourceIdentifier`.
+    ///
+    /// ```rust
+    /// use aws_arn::builder::ResourceBuilder;
+    ///
+    /// let builder = ResourceBuilder::from_qualified_str("layer:my-layer:3").unwrap();
+    /// assert_eq!(builder.build_qualified_id().to_string(), "layer:my-layer:3");
+    /// assert!(ResourceBuilder::from_qualified_str("layer::3").is_err());
+    /// ```
+    pub fn from_qualified_str(s: &str) -> ArnResult<Self> {
+        Self::split_str(s, PART_SEPARATOR)
+    }
+
+    /// Construct a new resource builder by splitting a resource path, such as
+    /// `user/division_abc/Bob`, on the '/' separator; this is the inverse of
+    /// `build_resource_path`. Returns an error if any component is not a valid
+    /// `ResourceIdentifier`.
+    ///
+    /// A leading '/', as in the API Gateway resource `/restapis/a1b2c3`, is kept on the first
+    /// component so that `build_resource_path` returns the same absolute path.
+    ///
+    /// ```rust
+    /// use aws_arn::builder::ResourceBuilder;
+    ///
+    /// let builder = ResourceBuilder::from_path_str("/restapis/a1b2c3").unwrap();
+    /// assert_eq!(builder.build_resource_path().to_string(), "/restapis/a1b2c3");
+    /// ```
+    pub fn from_path_str(s: &str) -> ArnResult<Self> {
+        match s.strip_prefix(PATH_SEPARATOR) {
+            Some(relative) => {
+                let mut builder = Self::split_str(relative, PATH_SEPARATOR)?;
+                let first = &mut builder.resource[0];
+                *first = ResourceIdentifier::new_unchecked(&format!("{}{}", PATH_SEPARATOR, first));
+                Ok(builder)
+            }
+            None => Self::split_str(s, PATH_SEPARATOR),
+        }
+    }
+
     /// Add the provided `ResourceIdentifier` to the inner list of components.
     pub fn add(&mut self, id: ResourceIdentifier) -> &mut Self {
         self.resource.push(id);
@@ -189,18 +309,144 @@ impl ResourceBuilder {
         self
     }
 
+    /// Add the provided `ResourceIdentifier` to the inner list of components, as
+    /// `qualified_name` does, returning the builder.
+    #[must_use]
+    pub fn with_qualified_name(mut self, id: ResourceIdentifier) -> Self {
+        self.resource.push(id);
+        self
+    }
+
+    /// Add the provided `Identifier` to the inner list of components, as `type_name` does,
+    /// returning the builder.
+    #[must_use]
+    pub fn with_type_name(mut self, id: Identifier) -> Self {
+        self.resource.push(id.into());
+        self
+    }
+
+    /// Add the provided `Identifier` to the inner list of components, as `resource_name` does,
+    /// returning the builder.
+    #[must_use]
+    pub fn with_resource_name(mut self, id: Identifier) -> Self {
+        self.resource.push(id.into());
+        self
+    }
+
+    /// Add the provided `Identifier` to the inner list of components, as `sub_resource_name`
+    /// does, returning the builder.
+    #[must_use]
+    pub fn with_sub_resource_name(mut self, id: Identifier) -> Self {
+        self.resource.push(id.into());
+        self
+    }
+
+    /// Add the provided integer version number to the inner list of components, as `version`
+    /// does, returning the builder.
+    #[must_use]
+    pub fn with_version(mut self, v: u32) -> Self {
+        self.resource
+            .push(Identifier::new_unchecked(&v.to_string()).into());
+        self
+    }
+
     /// Return the iner list of components as a resource identifier path.
-    pub fn build_resource_path(&mut self) -> ResourceIdentifier {
+    #[must_use]
+    pub fn build_resource_path(&self) -> ResourceIdentifier {
         ResourceIdentifier::from_path(&self.resource)
     }
 
     /// Return the iner list of components as a qualified resource identifier.
-    pub fn build_qualified_id(&mut self) -> ResourceIdentifier {
+    #[must_use]
+    pub fn build_qualified_id(&self) -> ResourceIdentifier {
         ResourceIdentifier::from_qualified(&self.resource)
     }
+
+    /// Return the inner list of components as a resource identifier, where `separators[i]` is
+    /// placed between the components `i` and `i + 1`. Returns an error if there is not exactly
+    /// one separator fewer than there are components, or if the result is not a valid resource
+    /// identifier, for example because there are no components.
+    ///
+    /// ```rust
+    /// use aws_arn::builder::{ResourceBuilder, Separator};
+    /// use aws_arn::{Identifier, IdentifierLike};
+    ///
+    /// let resource = ResourceBuilder::from_ids(&[
+    ///     Identifier::new_unchecked("table"),
+    ///     Identifier::new_unchecked("Name"),
+    ///     Identifier::new_unchecked("stream"),
+    ///     Identifier::new_unchecked("2024"),
+    ///     Identifier::new_unchecked("latest"),
+    /// ])
+    /// .build_mixed(&[
+    ///     Separator::Path,
+    ///     Separator::Path,
+    ///     Separator::Path,
+    ///     Separator::Qualifier,
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(resource.to_string(), "table/Name/stream/2024:latest");
+    /// ```
+    pub fn build_mixed(&self, separators: &[Separator]) -> ArnResult<ResourceIdentifier> {
+        let expected = self.resource.len().saturating_sub(1);
+        if separators.len() != expected {
+            return Err(ArnError::SeparatorCountMismatch {
+                expected,
+                actual: separators.len(),
+            });
+        }
+        let mut resource = String::new();
+        for (i, id) in self.resource.iter().enumerate() {
+            if i > 0 {
+                resource.push(separators[i - 1].as_char());
+            }
+            resource.push_str(id);
+        }
+        ResourceIdentifier::new(&resource)
+    }
+
+    fn split_str(s: &str, separator: char) -> ArnResult<Self> {
+        s.split(separator).map(ResourceIdentifier::new).collect()
+    }
+}
+
+/// The separator placed between two resource components by `ResourceBuilder::build_mixed`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Separator {
+    /// The path separator, '/'.
+    #[default]
+    Path,
+    /// The qualifier separator, ':'.
+    Qualifier,
+}
+
+impl Separator {
+    /// Return the character used for this separator.
+    #[must_use]
+    pub fn as_char(&self) -> char {
+        match self {
+            Self::Path => PATH_SEPARATOR,
+            Self::Qualifier => PART_SEPARATOR,
+        }
+    }
 }
 
+pub mod apigateway;
+pub mod athena;
+pub mod cloudformation;
+pub mod cloudwatch;
 pub mod cognito;
+pub mod costmanagement;
+pub mod detective;
+pub mod ec2;
+pub mod ecr;
+pub mod ecs;
+pub mod guardduty;
 pub mod iam;
 pub mod lambda;
+pub mod lex;
+pub mod logs;
+pub mod route53;
 pub mod s3;
+pub mod secretsmanager;
+pub mod states;
@@ -0,0 +1,40 @@
+//! Higher-level utilities to build ARNs for Amazon Route 53.
+//!
+//! Route 53 is a global service, and its ARNs include neither a region nor an account. When
+//! building Route 53 ARNs directly with [`ResourceName::builder`], finish with
+//! [`try_build`](crate::builder::ResourceNameBuilder::try_build) rather than `build` so that a
+//! region given by mistake is rejected.
+//!
+//! For more information, check out the [AWS documentation](https://docs.aws.amazon.com/service-authorization/latest/reference/list_amazonroute53.html#amazonroute53-resources-for-iam-policies)
+
+use crate::{
+    Identifier, IdentifierLike, Partition, ResourceIdentifier, ResourceName, Service::Route53,
+};
+
+///
+/// `arn:${Partition}:route53:::hostedzone/${Id}`
+///
+pub fn hosted_zone(partition: Partition, zone_id: Identifier) -> ResourceName {
+    ResourceName::builder()
+        .service(Route53)
+        .in_partition(partition)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("hostedzone"),
+            zone_id,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:route53:::healthcheck/${Id}`
+///
+pub fn health_check(partition: Partition, health_check_id: Identifier) -> ResourceName {
+    ResourceName::builder()
+        .service(Route53)
+        .in_partition(partition)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("healthcheck"),
+            health_check_id,
+        ]))
+        .build()
+}
@@ -2,9 +2,12 @@
 //!
 //! For more information, check out the [AWS documentation](https://docs.aws.amazon.com/IAM/latest/UserGuide/list_amazons3.html#amazons3-resources-for-iam-policies)
 
-use crate::types::Partition;
-use crate::types::Service::S3;
-use crate::{AccountId, Identifier, Region, ResourceIdentifier, ResourceName};
+use crate::pattern::Glob;
+use crate::{
+    AccountId, ArnError, ArnResult, Identifier, IdentifierLike, Partition, Region,
+    ResourceIdentifier, ResourceName,
+    Service::{S3Outposts, S3},
+};
 
 ///
 /// `arn:${Partition}:s3:::${BucketName}`
@@ -21,7 +24,7 @@ pub fn bucket_in(partition: Partition, bucket_name: Identifier) -> ResourceName
 /// `arn:aws:s3:::${BucketName}`
 ///
 pub fn bucket(bucket_name: Identifier) -> ResourceName {
-    bucket_in(Partition::default().into(), bucket_name)
+    bucket_in(Partition::default(), bucket_name)
 }
 
 ///
@@ -46,30 +49,33 @@ pub fn object_in(
 /// `arn:aws:s3:::${BucketName}/${ObjectName}`
 ///
 pub fn object(bucket_name: Identifier, object_name: Identifier) -> ResourceName {
-    object_in(Partition::default().into(), bucket_name, object_name)
+    object_in(Partition::default(), bucket_name, object_name)
 }
 
 ///
-/// `arn:aws:s3:::${BucketName}/${ObjectName}`
+/// `arn:${Partition}:s3:::${BucketName}/${ObjectName}`
 ///
-/// This function will panic if `bucket` is not an ResourceName for an S3 bucket.
+/// This function will return an error if `bucket` is not an ResourceName for an S3 bucket.
 ///
-pub fn object_from(bucket: &ResourceName, object_name: Identifier) -> ResourceName {
-    if bucket.service != S3.into() {
-        panic!(
-            "You can't make an S3 object from a {} ResourceName.",
-            bucket.service
-        );
+pub fn object_from(bucket: &ResourceName, object_name: Identifier) -> ArnResult<ResourceName> {
+    if bucket.service != S3 {
+        return Err(ArnError::UnexpectedService {
+            expected: S3.to_string(),
+            actual: bucket.service.to_string(),
+        });
     }
-    ResourceName {
+    Ok(ResourceName {
         resource: ResourceIdentifier::from_path(&[bucket.resource.clone(), object_name.into()]),
+        source: None,
         ..bucket.clone()
-    }
+    })
 }
 
 ///
 /// `arn:${Partition}:s3:${Region}:${Account}:job/${JobId}`
 ///
+/// The `job_id` is used as the whole resource, so it must include the `job/` prefix.
+///
 pub fn job_in(
     partition: Partition,
     region: Region,
@@ -88,6 +94,79 @@ pub fn job_in(
 ///
 /// `arn:aws:s3:${Region}:${Account}:job/${JobId}`
 ///
+/// As with [`job_in`], the `job_id` must include the `job/` prefix.
+///
 pub fn job(region: Region, account: AccountId, job_id: Identifier) -> ResourceName {
-    job_in(Partition::default().into(), region, account, job_id)
+    job_in(Partition::default(), region, account, job_id)
+}
+
+///
+/// `arn:${Partition}:s3:${Region}:${Account}:accesspoint/${AccessPointName}`
+///
+pub fn access_point(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    access_point_name: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(S3)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("accesspoint"),
+            access_point_name,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:s3outposts:${Region}:${Account}:outpost/${OutpostId}/bucket/${BucketName}`
+///
+pub fn outpost_bucket(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    outpost_id: Identifier,
+    bucket_name: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(S3Outposts)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_id_path(&[
+            Identifier::new_unchecked("outpost"),
+            outpost_id,
+            Identifier::new_unchecked("bucket"),
+            bucket_name,
+        ]))
+        .build()
+}
+
+/// Return `true` if the S3 ARN `key_arn` matches `pattern`, as in the `Resource` element of an
+/// IAM policy, else `false`.
+///
+/// The whole resource, bucket and object key together, is matched using the same glob
+/// semantics as [`ArnPattern::matches`](crate::ArnPattern::matches), as IAM does. As `*`
+/// matches `/`, `my-bucket/logs/*` matches every object under the `logs/` prefix, and `my-*`
+/// matches every object in every bucket whose name starts with `my-`, as well as the buckets
+/// themselves. A bucket pattern with no wildcard only matches the bucket itself, not the
+/// objects in it.
+///
+/// ```rust
+/// use aws_arn::builder::s3;
+/// use aws_arn::ResourceName;
+/// use std::str::FromStr;
+///
+/// let pattern = ResourceName::from_str("arn:aws:s3:::my-bucket/logs/*").unwrap();
+/// let object = ResourceName::from_str("arn:aws:s3:::my-bucket/logs/2024/01.gz").unwrap();
+/// assert!(s3::bucket_matches(&pattern, &object));
+/// ```
+pub fn bucket_matches(pattern: &ResourceName, key_arn: &ResourceName) -> bool {
+    if pattern.service != S3 || key_arn.service != S3 || pattern.partition != key_arn.partition {
+        return false;
+    }
+    Glob::new(&pattern.resource).matches(&key_arn.resource)
 }
@@ -0,0 +1,54 @@
+//! Higher-level utilities to build ARNs for AWS Secrets Manager.
+//!
+//! Note that when a secret is created AWS appends a hyphen and six random characters to the
+//! secret name in its ARN, for example `MySecret-a1b2c3`. Use `secret_with_suffix` to construct
+//! the complete ARN, or `secret` where a partial ARN is sufficient.
+//!
+//! For more information, check out the [AWS documentation](https://docs.aws.amazon.com/service-authorization/latest/reference/list_awssecretsmanager.html#awssecretsmanager-resources-for-iam-policies)
+
+use crate::{
+    AccountId, Identifier, IdentifierLike, Partition, Region, ResourceIdentifier, ResourceName,
+    Service::SecretsManager,
+};
+
+///
+/// `arn:${Partition}:secretsmanager:${Region}:${Account}:secret:${SecretName}`
+///
+/// Note that secret names commonly contain '/' characters, for example `prod/db/password`, so
+/// the name is taken as a `ResourceIdentifier`.
+///
+pub fn secret(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    secret_name: ResourceIdentifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(SecretsManager)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_qualified(&[
+            ResourceIdentifier::new_unchecked("secret"),
+            secret_name,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:secretsmanager:${Region}:${Account}:secret:${SecretName}-${Suffix}`
+///
+pub fn secret_with_suffix(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    secret_name: ResourceIdentifier,
+    suffix: Identifier,
+) -> ResourceName {
+    secret(
+        partition,
+        region,
+        account,
+        ResourceIdentifier::new_unchecked(&format!("{}-{}", secret_name, suffix)),
+    )
+}
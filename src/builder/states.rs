@@ -0,0 +1,54 @@
+//! Higher-level utilities to build ARNs for AWS Step Functions.
+//!
+//! Note that Step Functions resources use the `states` ARN namespace, not `stepfunctions`.
+//!
+//! For more information, check out the [AWS documentation](https://docs.aws.amazon.com/service-authorization/latest/reference/list_awsstepfunctions.html#awsstepfunctions-resources-for-iam-policies)
+
+use crate::{
+    AccountId, Identifier, IdentifierLike, Partition, Region, ResourceIdentifier, ResourceName,
+    Service::States,
+};
+
+///
+/// `arn:${Partition}:states:${Region}:${Account}:stateMachine:${StateMachineName}`
+///
+pub fn state_machine(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    state_machine_name: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(States)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_qualified_id(&[
+            Identifier::new_unchecked("stateMachine"),
+            state_machine_name,
+        ]))
+        .build()
+}
+
+///
+/// `arn:${Partition}:states:${Region}:${Account}:execution:${StateMachineName}:${ExecutionId}`
+///
+pub fn execution(
+    partition: Partition,
+    region: Region,
+    account: AccountId,
+    state_machine_name: Identifier,
+    execution_id: Identifier,
+) -> ResourceName {
+    ResourceName::builder()
+        .service(States)
+        .in_partition(partition)
+        .in_region(region)
+        .owned_by(account)
+        .is(ResourceIdentifier::from_qualified_id(&[
+            Identifier::new_unchecked("execution"),
+            state_machine_name,
+            execution_id,
+        ]))
+        .build()
+}
@@ -0,0 +1,185 @@
+//! Serialization support for [`ResourceName`](crate::ResourceName).
+//!
+//! A `ResourceName` is serialized as its canonical string. Human-readable formats, such as
+//! JSON, may also deserialize it from a map of its components, or the sequence of components
+//! written by version 0.3 of this crate; other formats, such as CBOR, only accept the string.
+//! In the case of a map or sequence each component is parsed from
+//! its string form using `FromStr` and, on failure, the name of the field is reported alongside
+//! the underlying [`ArnError`], so that an error in a configuration file points directly at the
+//! offending component. For compatibility with version 0.3 of this crate the `account_id`
+//! component may also be the map it was written as, such as `{"Account": "123456789012"}`.
+
+use crate::{
+    AccountId, AccountIdentifier, ArnError, Identifier, Partition, Region, ResourceIdentifier,
+    ResourceName, Service,
+};
+use serde::de::value::{MapAccessDeserializer, SeqAccessDeserializer};
+use serde::de::{Error, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+/// The map representation of a `ResourceName`.
+#[derive(Deserialize)]
+struct ResourceNameFields {
+    #[serde(deserialize_with = "partition")]
+    partition: Partition,
+    #[serde(deserialize_with = "service")]
+    service: Service,
+    #[serde(default, deserialize_with = "region")]
+    region: Option<Region>,
+    #[serde(default, deserialize_with = "account_id")]
+    account_id: Option<AccountIdentifier>,
+    #[serde(deserialize_with = "resource")]
+    resource: ResourceIdentifier,
+}
+
+/// The `account_id` component, either its string form or the map written by version 0.3.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AccountIdField {
+    String(String),
+    Legacy(LegacyAccountIdentifier),
+}
+
+/// The map form of an `AccountIdentifier` written by version 0.3.
+#[derive(Deserialize)]
+enum LegacyAccountIdentifier {
+    Account(String),
+    Service(String),
+}
+
+struct ResourceNameVisitor;
+
+impl<'de> Visitor<'de> for ResourceNameVisitor {
+    type Value = ResourceName;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("an ARN string or a map of ARN components")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        ResourceName::from_str(v).map_err(E::custom)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        ResourceNameFields::deserialize(MapAccessDeserializer::new(map)).map(Into::into)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        ResourceNameFields::deserialize(SeqAccessDeserializer::new(seq)).map(Into::into)
+    }
+}
+
+impl From<ResourceNameFields> for ResourceName {
+    fn from(fields: ResourceNameFields) -> Self {
+        Self {
+            partition: fields.partition,
+            service: fields.service,
+            region: fields.region,
+            account_id: fields.account_id,
+            resource: fields.resource,
+            source: None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ResourceName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ResourceNameVisitor)
+        } else {
+            deserializer.deserialize_str(ResourceNameVisitor)
+        }
+    }
+}
+
+impl Serialize for ResourceName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+fn parse_field<'de, D, T>(deserializer: D, field: &str) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr<Err = ArnError>,
+{
+    let s = String::deserialize(deserializer)?;
+    T::from_str(&s).map_err(|e| D::Error::custom(format!("invalid `{field}` component: {e}")))
+}
+
+fn parse_optional_field<'de, D, T>(deserializer: D, field: &str) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr<Err = ArnError>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(s) => T::from_str(&s)
+            .map(Some)
+            .map_err(|e| D::Error::custom(format!("invalid `{field}` component: {e}"))),
+    }
+}
+
+fn partition<'de, D>(deserializer: D) -> Result<Partition, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    parse_field(deserializer, "partition")
+}
+
+fn service<'de, D>(deserializer: D) -> Result<Service, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    parse_field(deserializer, "service")
+}
+
+fn region<'de, D>(deserializer: D) -> Result<Option<Region>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    parse_optional_field(deserializer, "region")
+}
+
+fn account_id<'de, D>(deserializer: D) -> Result<Option<AccountIdentifier>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let account_id = match Option::<AccountIdField>::deserialize(deserializer)? {
+        None => return Ok(None),
+        Some(AccountIdField::String(s)) => AccountIdentifier::from_str(&s),
+        Some(AccountIdField::Legacy(LegacyAccountIdentifier::Account(s))) => {
+            AccountId::from_str(&s).map(AccountIdentifier::Account)
+        }
+        Some(AccountIdField::Legacy(LegacyAccountIdentifier::Service(s))) => {
+            Identifier::from_str(&s).map(AccountIdentifier::Service)
+        }
+    };
+    account_id
+        .map(Some)
+        .map_err(|e| D::Error::custom(format!("invalid `account_id` component: {e}")))
+}
+
+fn resource<'de, D>(deserializer: D) -> Result<ResourceIdentifier, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    parse_field(deserializer, "resource")
+}
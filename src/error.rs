@@ -1,66 +1,326 @@
 //! Errors thrown by this crate.
 
-use std::fmt::Debug;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
 
 /// Any error that may arise from handling an ARN using this crate.
 /// Errors that may arise parsing an ResourceName with `FromStr::from_str()`.
-#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ArnError {
     /// String length must be greater than 8 corresponding to `"arn:::::"`.
-    #[error("provided string has length {0}, must be at least 8")]
     TooShort(usize),
     /// String length must be under 2048 characters..
-    #[error("provided string has length {0}, must be at most 2048")]
     TooLong(usize),
     /// Need at least 6 components.
-    #[error("provided string has {0} components, must have at least 6")]
     TooFewComponents(usize),
     /// Invalid `Identifier` string value.
-    #[error("{0} is not a valid identifier")]
     InvalidIdentifier(String),
     /// Missing the 'arn' prefix string.
-    #[error("provided string is missing the 'arn' prefix")]
     MissingPrefix,
     /// Missing the partition component.
-    #[error("provided string is missing the partition component")]
     MissingPartition,
     /// The partition component provided is not valid.
-    #[error("{0} is not a valid partition")]
     InvalidPartition(String),
     /// Missing the service component.
-    #[error("provided string is missing the partition component")]
     MissingService,
     /// The service component provided is not valid.
-    #[error("{0} is not a valid service")]
     InvalidService(String),
+    /// The service component is valid, but not the one required by the operation.
+    UnexpectedService {
+        /// The service required by the operation.
+        expected: String,
+        /// The service found in the ARN provided.
+        actual: String,
+    },
     /// Missing the region component.
-    #[error("provided string is missing the region component")]
     MissingRegion,
     /// The partition region provided is not valid.
-    #[error("{0} is not a valid region")]
     InvalidRegion(String),
+    /// The region is not in the partition, as checked by `ResourceName::parse_strict`.
+    PartitionRegionMismatch {
+        /// The partition found in the ARN provided.
+        partition: String,
+        /// The region found in the ARN provided.
+        region: String,
+    },
+    /// The service is global and its resources do not have a region.
+    RegionNotAllowed(String),
     /// The particular resource type does not allow region wildcards.
-    #[error("resource type {0} does not allow region wildcards")]
+    #[deprecated(note = "never returned, as no region wildcard rule is checked by this crate")]
     RegionWildcardNotAllowed(String),
     /// Missing the account id component.
-    #[error("provided string is missing the account ID component")]
     MissingAccountId,
     /// The partition account id provided is not valid.
-    #[error("{0} is not a valid account ID: must match ^[0-9]{{12}}$")]
     InvalidAccountId(String),
-    /// The particular resource type does not allow account wildcards.
-    #[error("resource type {0} does not allow account wildcards")]
+    /// The ARN has neither an account wildcard nor an `${account}` variable to expand, as
+    /// required by `ResourceName::expand_accounts`.
+    MissingAccountPlaceholder,
+    /// The account is a wildcard, which is not allowed in a policy principal, as checked by
+    /// `ResourceName::validate_principal`; the service of the ARN is included.
     AccountIdWildcardNotAllowed(String),
     /// Missing the resource component.
-    #[error("provided string is missing the resource component")]
     MissingResource,
     /// The partition resource provided is not valid, the name of the particular component
     /// in error is included.
-    #[error("{0} is not a valid resource")]
     InvalidResource(String),
+    /// The resource provided contains a control character, which is not allowed; the
+    /// offending character is included.
+    InvalidResourceCharacter(char),
     /// The particular resource type does not allow resource wildcards.
-    #[error("resource type {0} does not allow resource wildcards")]
+    #[deprecated(note = "never returned, as AWS allows wildcards in any part of a resource")]
     ResourceWildcardNotAllowed(String),
+    /// The value provided for a policy `Resource` element is not a string or array of strings.
+    InvalidPolicyResource(String),
+    /// The number of separators provided to `ResourceBuilder::build_mixed` is not one less than
+    /// the number of resource components.
+    SeparatorCountMismatch {
+        /// The number of separators required, one less than the number of components.
+        expected: usize,
+        /// The number of separators provided.
+        actual: usize,
+    },
+    /// A component of the string being parsed is invalid; `index` is the position of the
+    /// component, where 0 is the `arn` prefix and 5 is the resource, and `source` is the
+    /// error from parsing that component. The latter is not part of the `Display` text but is
+    /// returned by `std::error::Error::source`, so that error reporting libraries show the
+    /// full chain.
+    Component {
+        /// The position of the invalid component.
+        index: usize,
+        /// The error from parsing the component.
+        source: Box<ArnError>,
+    },
+}
+
+impl Display for ArnError {
+    #[allow(deprecated)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooShort(v) => write!(f, "provided string has length {v}, must be at least 8"),
+            Self::TooLong(v) => write!(f, "provided string has length {v}, must be at most 2048"),
+            Self::TooFewComponents(v) => write!(
+                f,
+                "provided string has {v} components, must have at least 6"
+            ),
+            Self::InvalidIdentifier(v) => write!(f, "{v} is not a valid identifier"),
+            Self::MissingPrefix => write!(f, "provided string is missing the 'arn' prefix"),
+            Self::MissingPartition => {
+                write!(f, "provided string is missing the partition component")
+            }
+            Self::InvalidPartition(v) => write!(f, "{v} is not a valid partition"),
+            Self::MissingService => write!(f, "provided string is missing the partition component"),
+            Self::InvalidService(v) => write!(f, "{v} is not a valid service"),
+            Self::UnexpectedService { expected, actual } => {
+                write!(f, "expected an ARN for service {expected}, not {actual}")
+            }
+            Self::MissingRegion => write!(f, "provided string is missing the region component"),
+            Self::InvalidRegion(v) => write!(f, "{v} is not a valid region"),
+            Self::PartitionRegionMismatch { region, partition } => {
+                write!(f, "region {region} is not in partition {partition}")
+            }
+            Self::RegionNotAllowed(v) => {
+                write!(f, "service {v} is global and does not allow a region")
+            }
+            Self::RegionWildcardNotAllowed(v) => {
+                write!(f, "resource type {v} does not allow region wildcards")
+            }
+            Self::MissingAccountId => {
+                write!(f, "provided string is missing the account ID component")
+            }
+            Self::InvalidAccountId(v) => {
+                write!(f, "{v} is not a valid account ID: must match ^[0-9]{{12}}$")
+            }
+            Self::MissingAccountPlaceholder => write!(
+                f,
+                "provided ARN has no account wildcard or ${{account}} variable to expand"
+            ),
+            Self::AccountIdWildcardNotAllowed(v) => write!(
+                f,
+                "account wildcards are not allowed in a principal ARN (service {v})"
+            ),
+            Self::MissingResource => write!(f, "provided string is missing the resource component"),
+            Self::InvalidResource(v) => write!(f, "{v} is not a valid resource"),
+            Self::InvalidResourceCharacter(v) => write!(
+                f,
+                "resource contains the control character {v:?}, which is not allowed"
+            ),
+            Self::ResourceWildcardNotAllowed(v) => {
+                write!(f, "resource type {v} does not allow resource wildcards")
+            }
+            Self::InvalidPolicyResource(v) => write!(
+                f,
+                "{v} is not a valid policy resource, must be a string or array of strings"
+            ),
+            Self::SeparatorCountMismatch { expected, actual } => write!(
+                f,
+                "expected {expected} separators between resource components, found {actual}"
+            ),
+            Self::Component { index, .. } => write!(
+                f,
+                "component {index} ({}) is invalid",
+                component_name(*index)
+            ),
+        }
+    }
+}
+
+impl Error for ArnError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Component { source, .. } => Some(&**source),
+            _ => None,
+        }
+    }
+}
+
+pub use code::ArnErrorCode;
+
+// The derives of `ArnErrorCode` refer to its deprecated variants, which is only allowed
+// for the whole module they are generated in.
+#[allow(deprecated)]
+mod code {
+    /// A stable, machine-readable code for each kind of [`ArnError`](super::ArnError), as
+    /// returned by [`ArnError::code`](super::ArnError::code). Unlike the `Display` text of an
+    /// error these codes will not change between releases, so they are suitable for matching
+    /// on or reporting to API clients. The `Display` form, and serialized form with the `serde`
+    /// feature, is the snake case name of the variant, for example `invalid_region`.
+    ///
+    /// This enum is non-exhaustive as new errors may be added over time; matches outside this
+    /// crate must include a wildcard arm.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        Hash,
+        strum::AsRefStr,
+        strum::Display,
+        strum::IntoStaticStr,
+    )]
+    #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+    #[strum(serialize_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum ArnErrorCode {
+        /// See [`ArnError::TooShort`](super::ArnError::TooShort).
+        TooShort,
+        /// See [`ArnError::TooLong`](super::ArnError::TooLong).
+        TooLong,
+        /// See [`ArnError::TooFewComponents`](super::ArnError::TooFewComponents).
+        TooFewComponents,
+        /// See [`ArnError::InvalidIdentifier`](super::ArnError::InvalidIdentifier).
+        InvalidIdentifier,
+        /// See [`ArnError::MissingPrefix`](super::ArnError::MissingPrefix).
+        MissingPrefix,
+        /// See [`ArnError::MissingPartition`](super::ArnError::MissingPartition).
+        MissingPartition,
+        /// See [`ArnError::InvalidPartition`](super::ArnError::InvalidPartition).
+        InvalidPartition,
+        /// See [`ArnError::MissingService`](super::ArnError::MissingService).
+        MissingService,
+        /// See [`ArnError::InvalidService`](super::ArnError::InvalidService).
+        InvalidService,
+        /// See [`ArnError::UnexpectedService`](super::ArnError::UnexpectedService).
+        UnexpectedService,
+        /// See [`ArnError::MissingRegion`](super::ArnError::MissingRegion).
+        MissingRegion,
+        /// See [`ArnError::InvalidRegion`](super::ArnError::InvalidRegion).
+        InvalidRegion,
+        /// See [`ArnError::PartitionRegionMismatch`](super::ArnError::PartitionRegionMismatch).
+        PartitionRegionMismatch,
+        /// See [`ArnError::RegionNotAllowed`](super::ArnError::RegionNotAllowed).
+        RegionNotAllowed,
+        /// See [`ArnError::RegionWildcardNotAllowed`](super::ArnError::RegionWildcardNotAllowed).
+        #[deprecated(note = "never returned, as no region wildcard rule is checked by this crate")]
+        RegionWildcardNotAllowed,
+        /// See [`ArnError::MissingAccountId`](super::ArnError::MissingAccountId).
+        MissingAccountId,
+        /// See [`ArnError::InvalidAccountId`](super::ArnError::InvalidAccountId).
+        InvalidAccountId,
+        /// See [`ArnError::MissingAccountPlaceholder`](super::ArnError::MissingAccountPlaceholder).
+        MissingAccountPlaceholder,
+        /// See [`ArnError::AccountIdWildcardNotAllowed`](super::ArnError::AccountIdWildcardNotAllowed).
+        AccountIdWildcardNotAllowed,
+        /// See [`ArnError::MissingResource`](super::ArnError::MissingResource).
+        MissingResource,
+        /// See [`ArnError::InvalidResource`](super::ArnError::InvalidResource).
+        InvalidResource,
+        /// See [`ArnError::InvalidResourceCharacter`](super::ArnError::InvalidResourceCharacter).
+        InvalidResourceCharacter,
+        /// See [`ArnError::ResourceWildcardNotAllowed`](super::ArnError::ResourceWildcardNotAllowed).
+        #[deprecated(note = "never returned, as AWS allows wildcards in any part of a resource")]
+        ResourceWildcardNotAllowed,
+        /// See [`ArnError::InvalidPolicyResource`](super::ArnError::InvalidPolicyResource).
+        InvalidPolicyResource,
+        /// See [`ArnError::SeparatorCountMismatch`](super::ArnError::SeparatorCountMismatch).
+        SeparatorCountMismatch,
+    }
+}
+
+impl ArnError {
+    /// Return the stable code for this error. A [`ArnError::Component`] error returns the
+    /// code of the error it wraps; use [`ArnError::component_index`] for its position.
+    ///
+    /// ```rust
+    /// use aws_arn::{ArnErrorCode, ResourceName};
+    /// use std::str::FromStr;
+    ///
+    /// let error = ResourceName::from_str("arn:aws:s3:nowhere::my-bucket").unwrap_err();
+    /// assert_eq!(error.code(), ArnErrorCode::InvalidRegion);
+    /// assert_eq!(error.code().to_string(), "invalid_region");
+    /// ```
+    #[allow(deprecated)]
+    pub fn code(&self) -> ArnErrorCode {
+        match self {
+            Self::TooShort(_) => ArnErrorCode::TooShort,
+            Self::TooLong(_) => ArnErrorCode::TooLong,
+            Self::TooFewComponents(_) => ArnErrorCode::TooFewComponents,
+            Self::InvalidIdentifier(_) => ArnErrorCode::InvalidIdentifier,
+            Self::MissingPrefix => ArnErrorCode::MissingPrefix,
+            Self::MissingPartition => ArnErrorCode::MissingPartition,
+            Self::InvalidPartition(_) => ArnErrorCode::InvalidPartition,
+            Self::MissingService => ArnErrorCode::MissingService,
+            Self::InvalidService(_) => ArnErrorCode::InvalidService,
+            Self::UnexpectedService { .. } => ArnErrorCode::UnexpectedService,
+            Self::MissingRegion => ArnErrorCode::MissingRegion,
+            Self::InvalidRegion(_) => ArnErrorCode::InvalidRegion,
+            Self::PartitionRegionMismatch { .. } => ArnErrorCode::PartitionRegionMismatch,
+            Self::RegionNotAllowed(_) => ArnErrorCode::RegionNotAllowed,
+            Self::RegionWildcardNotAllowed(_) => ArnErrorCode::RegionWildcardNotAllowed,
+            Self::MissingAccountId => ArnErrorCode::MissingAccountId,
+            Self::InvalidAccountId(_) => ArnErrorCode::InvalidAccountId,
+            Self::MissingAccountPlaceholder => ArnErrorCode::MissingAccountPlaceholder,
+            Self::AccountIdWildcardNotAllowed(_) => ArnErrorCode::AccountIdWildcardNotAllowed,
+            Self::MissingResource => ArnErrorCode::MissingResource,
+            Self::InvalidResource(_) => ArnErrorCode::InvalidResource,
+            Self::InvalidResourceCharacter(_) => ArnErrorCode::InvalidResourceCharacter,
+            Self::ResourceWildcardNotAllowed(_) => ArnErrorCode::ResourceWildcardNotAllowed,
+            Self::InvalidPolicyResource(_) => ArnErrorCode::InvalidPolicyResource,
+            Self::SeparatorCountMismatch { .. } => ArnErrorCode::SeparatorCountMismatch,
+            Self::Component { source, .. } => source.code(),
+        }
+    }
+
+    /// Return the position of the component in error, if this error was produced while
+    /// parsing a specific component, else `None`.
+    pub fn component_index(&self) -> Option<usize> {
+        match self {
+            Self::Component { index, .. } => Some(*index),
+            _ => None,
+        }
+    }
+}
+
+fn component_name(index: usize) -> &'static str {
+    match index {
+        0 => "prefix",
+        1 => "partition",
+        2 => "service",
+        3 => "region",
+        4 => "account",
+        _ => "resource",
+    }
 }
 
 pub type ArnResult<T> = Result<T, ArnError>;
@@ -14,21 +14,20 @@
 //! [`ResourceName`], [`AccountId`], [`Partition`], [`Service`], and [`ResourceIdentifier`].
 //!
 //! ```rust
-//! use aws_arn::{ResourceName, ResourceIdentifier};
-//! use aws_arn::{Partition, Region, Service};
+//! use aws_arn::{AccountId, ResourceName, ResourceIdentifier};
+//! use aws_arn::{Region, Service};
 //! use std::str::FromStr;
 //!
-//! let arn = ResourceName {
-//!     partition: Partition::default(),
-//!     service: Service::S3,
-//!     region: None,
-//!     account_id: None,
-//!     resource: ResourceIdentifier::from_str("my-s3-bucket-name").unwrap(),
-//! };
+//! let arn = ResourceName::regional(
+//!     Service::SimpleQueue,
+//!     Region::UsEast1,
+//!     AccountId::from_str("123456789012").unwrap(),
+//!     ResourceIdentifier::from_str("my-queue").unwrap(),
+//! );
 //! ```
 //!
-//! In the example above, as we are defining a minimal ResourceName. However, we could
-//! also use one of the defined constructor functions.
+//! In the example above the partition is the one containing the region. For a minimal
+//! ResourceName, with no region or account, use [`ResourceName::aws`].
 //!
 //! ```rust
 //! use aws_arn::{ResourceName, ResourceIdentifier};
@@ -57,9 +56,15 @@
 //! (ResourceName)](https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html) documentation.
 //!
 //! # Features
-//! * `serde`: enables (de)serialization using [`serde`](). This feature is enabled by default.
+//! * `serde`: enables (de)serialization using [`serde`](), and conversion to and from IAM policy
+//!   `Resource` values. This feature is enabled by default.
 //! * `builders`: enables fluent builders using [`bon`](). This feature is enabled by default.
-//!/
+//! * `proptest`: implements `proptest::arbitrary::Arbitrary` for [`ResourceName`], generating
+//!   values that round-trip through `Display` and `FromStr`.
+//! * `compact`: stores the inner string of [`Identifier`], [`AccountId`], and
+//!   [`ResourceIdentifier`] using [`compact_str`](https://docs.rs/compact_str), keeping short
+//!   components inline. These types still `Deref` to `str`, so the API does not change.
+//!
 
 #![warn(
     future_incompatible,
@@ -77,21 +82,46 @@
     unused_results
 )]
 
-#[cfg(feature = "builders")]
-use bon::builder;
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
 
 use std::str::FromStr;
 
 mod types;
 pub use types::{
-    AccountId, AccountIdentifier, Identifier, IdentifierLike, Partition, Region,
-    ResourceIdentifier, Service,
+    AccountId, AccountIdentifier, Continent, Identifier, IdentifierLike, Partition, Region,
+    ResourceIdentifier, ResourceShape, Service, ServiceCategory, Substitution, ARN_PREFIX,
+    MAX_IDENTIFIER_LENGTH, REQUIRED_COMPONENT_COUNT,
 };
-use types::{ARN_PREFIX, PART_SEPARATOR, REQUIRED_COMPONENT_COUNT};
+use types::{PART_SEPARATOR, PATH_SEPARATOR};
+
+const ACCOUNT_VARIABLE: &str = "${account}";
+
+const MAX_LENGTH: usize = 2048;
+
+const REDACTED_ACCOUNT: &str = "REDACTED";
+
+const AWS_MANAGED_ACCOUNT: &str = "aws";
+
+#[cfg(feature = "serde")]
+mod de;
+
+mod pattern;
+pub use pattern::{find_by_prefix, ArnPattern, ResourceNamePattern};
+
+#[cfg(feature = "serde")]
+mod policy;
+#[cfg(feature = "serde")]
+pub use policy::{from_policy_resource_json, to_policy_resource_json};
+
+mod rules;
+pub use rules::{ResourceRuleSet, ServiceRule};
+
+mod stream;
+pub use stream::{validate_stream, OutputFormat, StreamSummary};
 
 /// Amazon Resource Names (Arns) uniquely identify AWS resources. We require an ResourceName when you
 /// need to specify a resource unambiguously across all of AWS, such as in IAM policies,
@@ -108,31 +138,44 @@ use types::{ARN_PREFIX, PART_SEPARATOR, REQUIRED_COMPONENT_COUNT};
 ///
 /// From [ResourceName Format](https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#arns-syntax)
 ///
+/// `ResourceName` values are ordered by component, in the order partition, service, region,
+/// account, and resource. The original string, as returned by [`ResourceName::source`], is
+/// not considered when comparing or hashing. As that string is held in a private field, values
+/// are constructed by parsing, with the builder, or with constructors such as
+/// [`ResourceName::aws`], rather than as struct literals.
+///
+/// With the `serde` feature a `ResourceName` is serialized as its canonical string form, and
+/// may be deserialized from either that string or a map of its components.
+///
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "builders", derive(bon::Builder))]
+#[cfg_attr(
+    feature = "builders",
+    builder(finish_fn(doc {
+        /// Finish building the `ResourceName` **without** validating it, so that, for example, a
+        /// region given for a global service such as IAM is accepted. Use
+        /// [`try_build`](ResourceNameBuilder::try_build) to have the components checked by
+        /// [`ResourceName::validate`].
+    }))
+)]
 pub struct ResourceName {
     /// The partition that the resource is in. For standard AWS Regions, the partition is` aws`.
     /// If you have resources in other partitions, the partition is `aws-partitionname`. For
     /// example, the partition for resources in the China partition is `aws-cn`.
-    #[cfg(feature = "builders")]
-    #[builder(into, name = "in_partition", default = Partition::Aws)]
+    #[cfg_attr(feature = "builders", builder(into, name = "in_partition", default = Partition::Aws))]
     pub partition: Partition,
     /// The service namespace that identifies the AWS service.
-    #[cfg(feature = "builders")]
-    #[builder(into)]
+    #[cfg_attr(feature = "builders", builder(into))]
     pub service: Service,
     /// The AWS region that the resource resides in. Some resources - like S3 buckets - are considered
     /// "global", and thus the ARN does not require a region.
-    #[cfg(feature = "builders")]
-    #[builder(into, name = "in_region")]
+    #[cfg_attr(feature = "builders", builder(into, name = "in_region"))]
     pub region: Option<Region>,
     /// The ID of the AWS account that owns the resource, without the hyphens. For example,
     /// `123456789012`. Some resources, like S3 buckets, have ARNs that do not include the AWS
     /// account ID.
-    #[cfg(feature = "builders")]
-    #[builder(into, name = "in_account")]
+    #[cfg_attr(feature = "builders", builder(into, name = "in_account"))]
     pub account_id: Option<AccountIdentifier>,
     /// The content of this part of the ResourceName varies by service. A resource identifier can
     /// be the name or ID of the resource (for example, `user/Bob` or
@@ -140,12 +183,104 @@ pub struct ResourceName {
     /// identifiers include a parent resource
     /// (`sub-resource-type/parent-resource/sub-resource`) or a qualifier such as a
     /// version (`resource-type:resource-name:qualifier`).
-    #[cfg(feature = "builders")]
-    #[builder(into)]
+    #[cfg_attr(feature = "builders", builder(into))]
     pub resource: ResourceIdentifier,
+    /// The original string this value was parsed from, if any, see [`ResourceName::source`].
+    /// This field is not serialized and is ignored by comparison and hashing.
+    #[cfg_attr(feature = "builders", builder(skip))]
+    pub(crate) source: Option<Box<str>>,
+}
+
+impl PartialEq for ResourceName {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for ResourceName {}
+
+impl PartialOrd for ResourceName {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ResourceName {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl Hash for ResourceName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+/// A borrowed view of the components of a [`ResourceName`], as returned by
+/// [`ResourceName::components`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArnComponents<'a> {
+    /// The partition component.
+    pub partition: &'a str,
+    /// The service component.
+    pub service: &'a str,
+    /// The region component, if present.
+    pub region: Option<&'a str>,
+    /// The account component, if present.
+    pub account: Option<&'a str>,
+    /// The resource component.
+    pub resource: &'a str,
+}
+
+/// The resource component of a [`ResourceName`] split into its type, identifier, and
+/// qualifier, as returned by [`ResourceName::resource_parts`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceParts<'a> {
+    /// The resource type, the text before the first path or qualifier separator, if any.
+    pub type_: Option<&'a str>,
+    /// The resource identifier, which may itself be a path.
+    pub id: &'a str,
+    /// The qualifier, such as a version or alias, following the last qualifier separator
+    /// after the resource type, if any.
+    pub qualifier: Option<&'a str>,
+}
+
+/// The components of a [`ResourceName`] parsed before the first error, as returned by
+/// [`ResourceName::parse_partial`]. Components after the one in error are `None`; as the
+/// region and account may legitimately be empty these are also `None` when absent, so use
+/// the error to determine where parsing stopped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartialResourceName {
+    /// The partition component, if parsed.
+    pub partition: Option<Partition>,
+    /// The service component, if parsed.
+    pub service: Option<Service>,
+    /// The region component, if present and parsed.
+    pub region: Option<Region>,
+    /// The account component, if present and parsed.
+    pub account_id: Option<AccountIdentifier>,
+    /// The resource component, if parsed.
+    pub resource: Option<ResourceIdentifier>,
+}
+
+/// The general format of the resource component of a [`ResourceName`], as returned by
+/// [`ResourceName::arn_format`]. These are the three formats listed in the
+/// [AWS documentation](https://docs.aws.amazon.com/IAM/latest/UserGuide/reference-arns.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArnResourceFormat {
+    /// `arn:partition:service:region:account-id:resource-id`
+    Id,
+    /// `arn:partition:service:region:account-id:resource-type/resource-id`
+    TypeSlashId,
+    /// `arn:partition:service:region:account-id:resource-type:resource-id`
+    TypeColonId,
 }
 
 impl Display for ResourceName {
+    /// Format the canonical, single-line, form of this ARN. The alternate form, `{:#}`,
+    /// instead produces one labelled component per line for debugging, with `-` for an
+    /// absent region or account.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let region = self
             .region
@@ -156,11 +291,24 @@ impl Display for ResourceName {
             .clone()
             .map_or(String::from(""), |val| val.to_string());
 
-        write!(
-            f,
-            "{}:{}:{}:{}:{}:{}",
-            ARN_PREFIX, self.partition, self.service, region, account_id, self.resource,
-        )
+        if f.alternate() {
+            let or_dash = |s: &str| if s.is_empty() { "-" } else { s }.to_string();
+            write!(
+                f,
+                "partition: {}\nservice:   {}\nregion:    {}\naccount:   {}\nresource:  {}",
+                self.partition,
+                self.service,
+                or_dash(&region),
+                or_dash(&account_id),
+                self.resource,
+            )
+        } else {
+            write!(
+                f,
+                "{}:{}:{}:{}:{}:{}",
+                ARN_PREFIX, self.partition, self.service, region, account_id, self.resource,
+            )
+        }
     }
 }
 
@@ -172,33 +320,149 @@ impl FromStr for ResourceName {
     ///
     /// * `arn:partition:service:region:account-id: | resource part |`
     ///
+    /// A string with fewer than six components is rejected with
+    /// [`ArnError::TooFewComponents`] before the `arn` prefix is checked. The resource part
+    /// may itself contain `:` characters. An empty resource part is
+    /// rejected with [`ArnError::MissingResource`]. An invalid partition, service, region,
+    /// account, or resource is reported as [`ArnError::Component`], with the index of the
+    /// component in error.
+    ///
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.splitn(REQUIRED_COMPONENT_COUNT, PART_SEPARATOR).collect();
-        if parts.len() < REQUIRED_COMPONENT_COUNT {
-            return Err(ArnError::TooFewComponents(parts.len()));
-        } else if parts[0] != ARN_PREFIX {
-            return Err(ArnError::MissingPrefix);
-        }
+        Ok(ResourceName {
+            source: Some(s.into()),
+            ..parse_complete(split_complete(s)?)?
+        })
+    }
+}
 
-        let partition = Partition::from_str(parts[1])?;
-        let service = Service::from_str(parts[2])?;
-        let region = match parts[3] {
-            "" => None,
-            region => Some(Region::from_str(region)?),
-        };
-        let account_id = match parts[4] {
-            "" => None,
-            account_id => Some(AccountIdentifier::from_str(account_id)?),
-        };
-        let resource = ResourceIdentifier::from_str(parts[5])?;
+impl TryFrom<[&str; REQUIRED_COMPONENT_COUNT]> for ResourceName {
+    type Error = ArnError;
 
-        Ok(ResourceName {
+    /// Construct a `ResourceName` from its components, already split, validating each as
+    /// `FromStr` does. The first component must be the `arn` prefix.
+    ///
+    /// ```rust
+    /// use aws_arn::ResourceName;
+    /// use std::convert::TryFrom;
+    ///
+    /// let arn = ResourceName::try_from(["arn", "aws", "s3", "", "", "my-bucket"]).unwrap();
+    /// assert_eq!(arn.to_string(), "arn:aws:s3:::my-bucket");
+    /// ```
+    fn try_from(parts: [&str; REQUIRED_COMPONENT_COUNT]) -> Result<Self, Self::Error> {
+        parse_complete(parts.iter().copied())
+    }
+}
+
+// Split `s` into its components, checking that there are enough of them before any is parsed,
+// so that a string that is not an ARN at all reports `TooFewComponents` rather than
+// `MissingPrefix`.
+pub(crate) fn split_complete(s: &str) -> ArnResult<std::str::SplitN<'_, char>> {
+    let count = s.splitn(REQUIRED_COMPONENT_COUNT, PART_SEPARATOR).count();
+    if count < REQUIRED_COMPONENT_COUNT {
+        Err(ArnError::TooFewComponents(count))
+    } else {
+        Ok(s.splitn(REQUIRED_COMPONENT_COUNT, PART_SEPARATOR))
+    }
+}
+
+// Parse `parts` into a complete `ResourceName`, as `parse_components` does.
+fn parse_complete<'a>(parts: impl IntoIterator<Item = &'a str>) -> ArnResult<ResourceName> {
+    let mut partial = PartialResourceName::default();
+    parse_components(parts, &mut partial)?;
+    match partial {
+        PartialResourceName {
+            partition: Some(partition),
+            service: Some(service),
+            region,
+            account_id,
+            resource: Some(resource),
+        } => Ok(ResourceName {
             account_id,
             partition,
             region,
             service,
             resource,
-        })
+            source: None,
+        }),
+        _ => unreachable!("every required component is parsed when there is no error"),
+    }
+}
+
+// Parse `parts`, the `arn` prefix followed by each component, into `partial` in order, stopping
+// at the first error.
+fn parse_components<'a>(
+    parts: impl IntoIterator<Item = &'a str>,
+    partial: &mut PartialResourceName,
+) -> ArnResult<()> {
+    let mut parts = Components::new(parts)?;
+
+    partial.partition = Some(Partition::from_str(parts.next()?).map_err(in_component(1))?);
+    partial.service = Some(Service::from_str(parts.next()?).map_err(in_component(2))?);
+    partial.region = match parts.next()? {
+        "" => None,
+        region => Some(Region::from_str(region).map_err(in_component(3))?),
+    };
+    partial.account_id = match parts.next()? {
+        "" => None,
+        account_id => Some(AccountIdentifier::from_str(account_id).map_err(in_component(4))?),
+    };
+    partial.resource = Some(parse_resource(parts.next()?)?);
+    Ok(())
+}
+
+// The components of an ARN, after the `arn` prefix, read in order. Every parser reads its
+// input through this so that they all report the same error for the same malformed input.
+pub(crate) struct Components<I> {
+    parts: I,
+    count: usize,
+}
+
+impl<'a, I: Iterator<Item = &'a str>> Components<I> {
+    // Check that the first of `parts` is the `arn` prefix.
+    pub(crate) fn new(parts: impl IntoIterator<Item = &'a str, IntoIter = I>) -> ArnResult<Self> {
+        let mut parts = parts.into_iter();
+        if parts.next() != Some(ARN_PREFIX) {
+            return Err(ArnError::MissingPrefix);
+        }
+        Ok(Self { parts, count: 1 })
+    }
+
+    // Return the next component, or an error if there are no more.
+    pub(crate) fn next(&mut self) -> ArnResult<&'a str> {
+        let part = self
+            .parts
+            .next()
+            .ok_or(ArnError::TooFewComponents(self.count))?;
+        self.count += 1;
+        Ok(part)
+    }
+}
+
+// Parse the final, resource, component, which must not be empty.
+pub(crate) fn parse_resource(resource: &str) -> ArnResult<ResourceIdentifier> {
+    match resource {
+        "" => Err(ArnError::MissingResource),
+        resource => ResourceIdentifier::from_str(resource).map_err(in_component(5)),
+    }
+}
+
+// Wrap an error from parsing a single component with the index of that component.
+pub(crate) fn in_component(index: usize) -> impl FnOnce(ArnError) -> ArnError {
+    move |e| ArnError::Component {
+        index,
+        source: Box::new(e),
+    }
+}
+
+impl From<ResourceName> for String {
+    fn from(v: ResourceName) -> Self {
+        v.to_string()
+    }
+}
+
+impl From<&ResourceName> for String {
+    fn from(v: &ResourceName) -> Self {
+        v.to_string()
     }
 }
 
@@ -210,39 +474,805 @@ impl From<AccountId> for ResourceName {
             region: None,
             resource: ResourceIdentifier::from_str("root").unwrap(),
             service: Service::IdentityAccessManagement,
+            source: None,
         }
     }
 }
 
 impl ResourceName {
     /// Construct a minimal `ResourceName` value with simply a service and resource.
+    ///
+    /// This is identical to [`ResourceName::aws`], which should be preferred as it makes the
+    /// choice of partition explicit; this constructor is retained for compatibility.
+    #[must_use]
     pub fn new(service: Service, resource: ResourceIdentifier) -> Self {
+        Self::aws(service, resource)
+    }
+
+    /// Construct a minimal `ResourceName` value with simply a service and resource in the `aws`
+    /// partition, with no region or account. This suits global resources such as S3 buckets;
+    /// for resources that live in a region use [`ResourceName::regional`].
+    ///
+    /// ```rust
+    /// use aws_arn::{ResourceIdentifier, ResourceName, Service};
+    /// use std::str::FromStr;
+    ///
+    /// let bucket = ResourceName::aws(Service::S3, ResourceIdentifier::from_str("my-bucket").unwrap());
+    /// assert_eq!(bucket.to_string(), "arn:aws:s3:::my-bucket");
+    /// ```
+    #[must_use]
+    pub fn aws(service: Service, resource: ResourceIdentifier) -> Self {
         Self {
             partition: Partition::Aws,
             service,
             region: None,
             account_id: None,
             resource,
+            source: None,
         }
     }
 
-    /// Construct a minimal `ResourceName` value with simply a service and resource in the `aws` partition.
-    pub fn aws(service: Service, resource: ResourceIdentifier) -> Self {
+    /// Construct a `ResourceName` for the resource named `resource` in `service`, as with
+    /// [`ResourceName::aws`], for contexts such as an S3 client where only the resource name
+    /// is given. The resource is parsed and the result validated, so an error is returned for
+    /// services, such as regional ones, whose ARNs need more than these defaults.
+    ///
+    /// ```rust
+    /// use aws_arn::{ArnError, ResourceName, Service};
+    ///
+    /// let bucket = ResourceName::for_service(Service::S3, "my-bucket").unwrap();
+    /// assert_eq!(bucket.to_string(), "arn:aws:s3:::my-bucket");
+    /// assert_eq!(
+    ///     ResourceName::for_service(Service::SimpleQueue, "my-queue"),
+    ///     Err(ArnError::MissingRegion)
+    /// );
+    /// ```
+    pub fn for_service(service: Service, resource: &str) -> ArnResult<Self> {
+        let arn = Self::aws(service, ResourceIdentifier::from_str(resource)?);
+        arn.validate()?;
+        Ok(arn)
+    }
+
+    /// Construct a `ResourceName` value for a resource owned by `account` in `region`. The
+    /// partition is the one containing `region`, see [`Partition::from_region`].
+    ///
+    /// ```rust
+    /// use aws_arn::{AccountId, Region, ResourceIdentifier, ResourceName, Service};
+    /// use std::str::FromStr;
+    ///
+    /// let queue = ResourceName::regional(
+    ///     Service::SimpleQueue,
+    ///     Region::CnNorth1,
+    ///     AccountId::from_str("123456789012").unwrap(),
+    ///     ResourceIdentifier::from_str("my-queue").unwrap(),
+    /// );
+    /// assert_eq!(queue.to_string(), "arn:aws-cn:sqs:cn-north-1:123456789012:my-queue");
+    /// ```
+    #[must_use]
+    pub fn regional(
+        service: Service,
+        region: Region,
+        account: AccountId,
+        resource: ResourceIdentifier,
+    ) -> Self {
         Self {
-            partition: Partition::Aws,
+            partition: Partition::from_region(&region),
             service,
-            region: None,
-            account_id: None,
+            region: Some(region),
+            account_id: Some(AccountIdentifier::Account(account)),
+            resource,
+            source: None,
+        }
+    }
+
+    /// Parse a `ResourceName` from an ARN string as returned by the AWS SDK crates, which
+    /// represent ARNs as `String` values. This is equivalent to `FromStr`; to pass a value
+    /// back to the SDK use `String::from`, which produces the canonical form.
+    ///
+    /// ```rust
+    /// use aws_arn::ResourceName;
+    ///
+    /// let from_sdk = String::from("arn:aws:sqs:us-east-1:123456789012:my-queue");
+    /// let arn = ResourceName::try_from_sdk(&from_sdk).unwrap();
+    /// let to_sdk = String::from(arn);
+    /// assert_eq!(to_sdk, from_sdk);
+    /// ```
+    pub fn try_from_sdk(s: &str) -> ArnResult<Self> {
+        Self::from_str(s)
+    }
+
+    /// Parse each line of `input` as a `ResourceName`, returning the 1-based line number with
+    /// each result. Blank lines, and comment lines starting with `#`, are skipped.
+    ///
+    /// ```rust
+    /// use aws_arn::ResourceName;
+    ///
+    /// let input = "# buckets\narn:aws:s3:::my-bucket\n\nnot-an-arn\n";
+    /// let results: Vec<_> = ResourceName::parse_lines(input).collect();
+    /// assert_eq!(results.len(), 2);
+    /// assert_eq!(results[0].0, 2);
+    /// assert!(results[0].1.is_ok());
+    /// assert_eq!(results[1].0, 4);
+    /// assert!(results[1].1.is_err());
+    /// ```
+    pub fn parse_lines(input: &str) -> impl Iterator<Item = (usize, ArnResult<Self>)> + '_ {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !stream::is_ignored_line(line))
+            .map(|(index, line)| (index + 1, Self::from_str(line)))
+    }
+
+    /// Parse a `ResourceName` from a string, ignoring any leading and trailing ASCII whitespace,
+    /// such as a trailing newline. Whitespace within the string is not affected, and so is only
+    /// accepted where the component allows it.
+    ///
+    /// Note that `FromStr` does not trim the string as the resource component may legitimately
+    /// end with a space.
+    ///
+    /// ```rust
+    /// use aws_arn::ResourceName;
+    ///
+    /// let arn = ResourceName::parse_trimmed(" arn:aws:s3:::my-bucket \n").unwrap();
+    /// assert_eq!(arn.to_string(), "arn:aws:s3:::my-bucket");
+    /// ```
+    pub fn parse_trimmed(s: &str) -> ArnResult<Self> {
+        Self::from_str(s.trim_matches(|c: char| c.is_ascii_whitespace()))
+    }
+
+    /// Parse as much of a `ResourceName` from a string as possible, returning the components
+    /// parsed before the first error along with that error, if any. This is useful for
+    /// interactive validation, where the user may be shown which components are correct.
+    ///
+    /// ```rust
+    /// use aws_arn::{Partition, ResourceName};
+    ///
+    /// let (partial, error) = ResourceName::parse_partial("arn:aws:s3:nowhere:");
+    /// assert_eq!(partial.partition, Some(Partition::Aws));
+    /// assert_eq!(error.unwrap().component_index(), Some(3));
+    /// ```
+    #[must_use]
+    pub fn parse_partial(s: &str) -> (PartialResourceName, Option<ArnError>) {
+        let mut partial = PartialResourceName::default();
+        let error = parse_components(
+            s.splitn(REQUIRED_COMPONENT_COUNT, PART_SEPARATOR),
+            &mut partial,
+        )
+        .err();
+        (partial, error)
+    }
+
+    /// Parse a `ResourceName` from a string, as `FromStr` does, and additionally check that the
+    /// region, if present, is in the partition according to [`Partition::from_region`]. An
+    /// inconsistent pair usually indicates a malformed ARN.
+    ///
+    /// ```rust
+    /// use aws_arn::{ArnError, ResourceName};
+    ///
+    /// assert!(ResourceName::parse_strict("arn:aws-us-gov:sqs:us-gov-west-1:123456789012:q").is_ok());
+    /// assert_eq!(
+    ///     ResourceName::parse_strict("arn:aws:sqs:us-gov-west-1:123456789012:q"),
+    ///     Err(ArnError::PartitionRegionMismatch {
+    ///         partition: "aws".to_string(),
+    ///         region: "us-gov-west-1".to_string(),
+    ///     })
+    /// );
+    /// ```
+    pub fn parse_strict(s: &str) -> ArnResult<Self> {
+        let arn = Self::from_str(s)?;
+        match &arn.region {
+            Some(region) if Partition::from_region(region) != arn.partition => {
+                Err(ArnError::PartitionRegionMismatch {
+                    partition: arn.partition.to_string(),
+                    region: region.to_string(),
+                })
+            }
+            _ => Ok(arn),
+        }
+    }
+
+    /// Return a copy of this `ResourceName` with the partition replaced.
+    ///
+    /// Like the other `with_*` methods this consumes `self` rather than modifying it, so
+    /// discarding the result is a warning.
+    ///
+    /// ```rust,compile_fail
+    /// #![deny(unused_must_use)]
+    /// use aws_arn::{Partition, ResourceName};
+    /// use std::str::FromStr;
+    ///
+    /// let arn = ResourceName::from_str("arn:aws:s3:::my-bucket").unwrap();
+    /// arn.with_partition(Partition::AwsChina);
+    /// ```
+    #[must_use]
+    pub fn with_partition(self, partition: Partition) -> Self {
+        Self {
+            partition,
+            source: None,
+            ..self
+        }
+    }
+
+    /// Return a copy of this `ResourceName` with the region replaced, or removed if `None`.
+    #[must_use]
+    pub fn with_region(self, region: Option<Region>) -> Self {
+        Self {
+            region,
+            source: None,
+            ..self
+        }
+    }
+
+    /// Return a copy of this `ResourceName` in `region`, with the partition replaced by the one
+    /// containing that region, see `Partition::from_region`. This avoids creating a
+    /// partition/region mismatch when moving an ARN between partitions.
+    ///
+    /// ```rust
+    /// use aws_arn::{Partition, Region, ResourceName};
+    /// use std::str::FromStr;
+    ///
+    /// let arn = ResourceName::from_str("arn:aws:sqs:us-east-1:123456789012:queue")
+    ///     .unwrap()
+    ///     .with_region_fixing_partition(Region::UsGovWest1);
+    /// assert_eq!(arn.partition, Partition::AwsUsGov);
+    /// assert_eq!(arn.to_string(), "arn:aws-us-gov:sqs:us-gov-west-1:123456789012:queue");
+    /// ```
+    #[must_use]
+    pub fn with_region_fixing_partition(self, region: Region) -> Self {
+        Self {
+            partition: Partition::from_region(&region),
+            region: Some(region),
+            source: None,
+            ..self
+        }
+    }
+
+    /// Return a copy of this `ResourceName` with the account replaced, or removed if `None`.
+    #[must_use]
+    pub fn with_account(self, account_id: Option<AccountIdentifier>) -> Self {
+        Self {
+            account_id,
+            source: None,
+            ..self
+        }
+    }
+
+    /// Return a copy of this `ResourceName` with the account removed.
+    #[must_use]
+    pub fn without_account(self) -> Self {
+        self.with_account(None)
+    }
+
+    /// Return a copy of this `ResourceName` with the resource replaced.
+    #[must_use]
+    pub fn with_resource(self, resource: ResourceIdentifier) -> Self {
+        Self {
             resource,
+            source: None,
+            ..self
+        }
+    }
+
+    /// Return a copy of this `ResourceName` with the resource replaced by the result of
+    /// applying `f` to it. Any error from `f` is returned, as is an error if the new resource
+    /// is not a valid resource identifier.
+    ///
+    /// ```rust
+    /// use aws_arn::{ResourceIdentifier, ResourceName};
+    /// use std::str::FromStr;
+    ///
+    /// let arn = ResourceName::from_str("arn:aws:s3:::my-bucket/Photos").unwrap();
+    /// let arn = arn
+    ///     .map_resource(|resource| ResourceIdentifier::from_str(&resource.to_lowercase()))
+    ///     .unwrap();
+    /// assert_eq!(arn.to_string(), "arn:aws:s3:::my-bucket/photos");
+    /// ```
+    pub fn map_resource<F>(mut self, f: F) -> ArnResult<Self>
+    where
+        F: FnOnce(ResourceIdentifier) -> ArnResult<ResourceIdentifier>,
+    {
+        let resource = f(std::mem::take(&mut self.resource))?;
+        if ResourceIdentifier::is_valid(&resource) {
+            Ok(self.with_resource(resource))
+        } else {
+            Err(ArnError::InvalidResource(resource.to_string()))
         }
     }
 
+    /// Return a copy of this `ResourceName` with empty path segments removed from the resource,
+    /// collapsing repeated path separators and stripping any trailing ones, so that
+    /// `my-bucket//logs/` becomes `my-bucket/logs`. A leading path separator, as in API Gateway
+    /// resources, is kept.
+    ///
+    /// This is never applied automatically: S3 object keys may legitimately contain empty
+    /// segments, so normalizing an object ARN can name a different object.
+    ///
+    /// ```rust
+    /// use aws_arn::ResourceName;
+    /// use std::str::FromStr;
+    ///
+    /// let arn = ResourceName::from_str("arn:aws:s3:::my-bucket//logs/").unwrap();
+    /// assert_eq!(arn.normalize_path().to_string(), "arn:aws:s3:::my-bucket/logs");
+    /// ```
+    #[must_use]
+    pub fn normalize_path(self) -> Self {
+        let mut normalized = String::with_capacity(self.resource.len());
+        if self.resource.is_absolute_path() {
+            normalized.push(PATH_SEPARATOR);
+        }
+        for segment in self.resource.path_segments().filter(|s| !s.is_empty()) {
+            if !normalized.is_empty() && !normalized.ends_with(PATH_SEPARATOR) {
+                normalized.push(PATH_SEPARATOR);
+            }
+            normalized.push_str(segment);
+        }
+        if normalized.is_empty() || normalized == *self.resource {
+            self
+        } else {
+            self.with_resource(ResourceIdentifier::new_unchecked(&normalized))
+        }
+    }
+
+    /// Return the original string this value was parsed from, if it was constructed by
+    /// `FromStr` and has not since been modified. As parsing normalizes components, for
+    /// example the case of the service, this may differ from the `Display` form.
+    #[must_use]
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    // The components that identify this value, used for comparison and hashing.
+    #[allow(clippy::type_complexity)]
+    fn key(
+        &self,
+    ) -> (
+        &Partition,
+        &Service,
+        &Option<Region>,
+        &Option<AccountIdentifier>,
+        &ResourceIdentifier,
+    ) {
+        (
+            &self.partition,
+            &self.service,
+            &self.region,
+            &self.account_id,
+            &self.resource,
+        )
+    }
+
+    /// Return a borrowed view of each component of this ARN, without allocating.
+    #[must_use]
+    pub fn components(&self) -> ArnComponents<'_> {
+        ArnComponents {
+            partition: self.partition.as_ref(),
+            service: self.service.as_str(),
+            region: self.region.as_ref().map(AsRef::as_ref),
+            account: self.account_id.as_deref(),
+            resource: &self.resource,
+        }
+    }
+
+    /// Return the length, in bytes, of the string form of this ARN, without allocating.
+    ///
+    /// ```rust
+    /// use aws_arn::ResourceName;
+    /// use std::str::FromStr;
+    ///
+    /// let arn = ResourceName::from_str("arn:aws:s3:::my-bucket").unwrap();
+    /// assert_eq!(arn.byte_len(), arn.to_string().len());
+    /// ```
+    #[must_use]
+    pub fn byte_len(&self) -> usize {
+        let components = self.components();
+        ARN_PREFIX.len()
+            + components.partition.len()
+            + components.service.len()
+            + components.region.map_or(0, str::len)
+            + components.account.map_or(0, str::len)
+            + components.resource.len()
+            + REQUIRED_COMPONENT_COUNT
+            - 1
+    }
+
+    // The length of the string form of this ARN in characters, which is how AWS states the
+    // 2048 limit checked by `validate`; only the account and resource may hold non-ASCII text.
+    fn char_len(&self) -> usize {
+        let components = self.components();
+        self.byte_len() - components.account.map_or(0, str::len) - components.resource.len()
+            + components
+                .account
+                .map_or(0, |account| account.chars().count())
+            + components.resource.chars().count()
+    }
+
+    /// Return the string form of this ARN with the account, if present, replaced by
+    /// `REDACTED`, suitable for writing to shared logs.
+    ///
+    /// ```rust
+    /// use aws_arn::ResourceName;
+    /// use std::str::FromStr;
+    ///
+    /// let arn = ResourceName::from_str("arn:aws:sqs:us-east-1:123456789012:my-queue").unwrap();
+    /// assert_eq!(arn.redacted(), "arn:aws:sqs:us-east-1:REDACTED:my-queue");
+    /// ```
+    #[must_use]
+    pub fn redacted(&self) -> String {
+        let components = self.components();
+        format!(
+            "{}:{}:{}:{}:{}:{}",
+            ARN_PREFIX,
+            components.partition,
+            components.service,
+            components.region.unwrap_or_default(),
+            components.account.map_or("", |_| REDACTED_ACCOUNT),
+            components.resource,
+        )
+    }
+
+    /// Return the resource type, the text of the resource component before the first path
+    /// or qualifier separator, if it has one. Resource types may contain hyphens, so
+    /// `event-source-mapping:uuid` returns `event-source-mapping`. A resource with neither
+    /// separator returns `None`.
+    #[must_use]
+    pub fn resource_type(&self) -> Option<&str> {
+        self.resource
+            .split_once([PATH_SEPARATOR, PART_SEPARATOR])
+            .map(|(resource_type, _)| resource_type)
+    }
+
+    /// Return the general format of the resource component, determined by whichever of the
+    /// path or qualifier separators appears first. Unlike [`ResourceIdentifier::shape`], which
+    /// reports every separator present, `type/name:qualifier` is `TypeSlashId`.
+    ///
+    /// ```rust
+    /// use aws_arn::{ArnResourceFormat, ResourceName};
+    /// use std::str::FromStr;
+    ///
+    /// let arn = ResourceName::from_str("arn:aws:iam::123456789012:user/Bob").unwrap();
+    /// assert_eq!(arn.arn_format(), ArnResourceFormat::TypeSlashId);
+    /// ```
+    #[must_use]
+    pub fn arn_format(&self) -> ArnResourceFormat {
+        match self
+            .resource
+            .chars()
+            .find(|&c| c == PATH_SEPARATOR || c == PART_SEPARATOR)
+        {
+            None => ArnResourceFormat::Id,
+            Some(PATH_SEPARATOR) => ArnResourceFormat::TypeSlashId,
+            Some(_) => ArnResourceFormat::TypeColonId,
+        }
+    }
+
+    /// Split the resource component into its type, identifier, and qualifier, handling the
+    /// shapes `id`, `type/id`, `type:id`, and `type:id:qualifier`. A resource without a
+    /// separator has only an identifier, and a qualifier is only recognized after a type.
+    ///
+    /// ```rust
+    /// use aws_arn::{ResourceName, ResourceParts};
+    /// use std::str::FromStr;
+    ///
+    /// let arn = ResourceName::from_str("arn:aws:lambda:us-east-1:123456789012:layer:my-layer:3")
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     arn.resource_parts(),
+    ///     ResourceParts { type_: Some("layer"), id: "my-layer", qualifier: Some("3") }
+    /// );
+    /// ```
+    #[must_use]
+    pub fn resource_parts(&self) -> ResourceParts<'_> {
+        match self.resource.split_once([PATH_SEPARATOR, PART_SEPARATOR]) {
+            None => ResourceParts {
+                type_: None,
+                id: &self.resource,
+                qualifier: None,
+            },
+            Some((type_, rest)) => match rest.rsplit_once(PART_SEPARATOR) {
+                Some((id, qualifier)) => ResourceParts {
+                    type_: Some(type_),
+                    id,
+                    qualifier: Some(qualifier),
+                },
+                None => ResourceParts {
+                    type_: Some(type_),
+                    id: rest,
+                    qualifier: None,
+                },
+            },
+        }
+    }
+
+    /// Return a low-cardinality key for this ARN, suitable for use in metrics or logging, of
+    /// the form `{service}:{resource_type}`, or just `{service}` if the resource has no type.
+    ///
+    /// ```rust
+    /// use aws_arn::ResourceName;
+    /// use std::str::FromStr;
+    ///
+    /// let arn = ResourceName::from_str("arn:aws:iam::123456789012:user/Bob").unwrap();
+    /// assert_eq!(arn.metric_key(), "iam:user");
+    /// ```
+    #[must_use]
+    pub fn metric_key(&self) -> String {
+        match self.resource_type() {
+            Some(resource_type) => format!("{}{}{}", self.service, PART_SEPARATOR, resource_type),
+            None => self.service.to_string(),
+        }
+    }
+
+    /// Return the resource component without its leading resource type, if it has one. The
+    /// type is the text before the first path or qualifier separator, so `user/Bob` returns
+    /// `Bob` and `layer:name:3` returns `name:3`. A resource with neither separator returns
+    /// `None`.
+    #[must_use]
+    pub fn resource_without_type(&self) -> Option<&str> {
+        self.resource
+            .split_once([PATH_SEPARATOR, PART_SEPARATOR])
+            .map(|(_, id)| id)
+    }
+
+    /// Return `true` if this ARN and `other` name the same logical resource, ignoring their
+    /// region and account, else `false`. Partitions must still match, as resource names are
+    /// only unique within a partition. Unlike `PartialEq` this treats copies of a resource
+    /// in different accounts or regions as the same.
+    ///
+    /// ```rust
+    /// use aws_arn::ResourceName;
+    /// use std::str::FromStr;
+    ///
+    /// let lhs = ResourceName::from_str("arn:aws:sqs:us-east-1:123456789012:queue").unwrap();
+    /// let rhs = ResourceName::from_str("arn:aws:sqs:eu-west-1:210987654321:queue").unwrap();
+    /// assert!(lhs.same_resource(&rhs));
+    /// assert_ne!(lhs, rhs);
+    /// ```
+    #[must_use]
+    pub fn same_resource(&self, other: &ResourceName) -> bool {
+        self.partition == other.partition
+            && self.service == other.service
+            && self.resource == other.resource
+    }
+
+    /// Return `true` if this ARN and `other` are equal when the account and resource
+    /// components are compared ignoring ASCII case, else `false`. The partition, service, and
+    /// region are already case-insensitive when parsed. `PartialEq` remains case-sensitive; this
+    /// is for services that treat resource names case-insensitively.
+    ///
+    /// ```rust
+    /// use aws_arn::ResourceName;
+    /// use std::str::FromStr;
+    ///
+    /// let lhs = ResourceName::from_str("arn:aws:sqs:us-east-1:123456789012:MyQueue").unwrap();
+    /// let rhs = ResourceName::from_str("arn:AWS:SQS:US-EAST-1:123456789012:myqueue").unwrap();
+    /// assert!(lhs.eq_ignore_ascii_case(&rhs));
+    /// assert_ne!(lhs, rhs);
+    /// ```
+    #[must_use]
+    pub fn eq_ignore_ascii_case(&self, other: &ResourceName) -> bool {
+        self.partition == other.partition
+            && self.service == other.service
+            && self.region == other.region
+            && match (&self.account_id, &other.account_id) {
+                (Some(lhs), Some(rhs)) => lhs.eq_ignore_ascii_case(rhs),
+                (None, None) => true,
+                _ => false,
+            }
+            && self.resource.eq_ignore_ascii_case(&other.resource)
+    }
+
+    /// Return the partition component of this ARN.
+    #[must_use]
+    pub fn partition(&self) -> &Partition {
+        &self.partition
+    }
+
+    /// Return the service component of this ARN.
+    #[must_use]
+    pub fn service(&self) -> &Service {
+        &self.service
+    }
+
+    /// Return the region component of this ARN, if present.
+    #[must_use]
+    pub fn region(&self) -> Option<&Region> {
+        self.region.as_ref()
+    }
+
+    /// Return the account component of this ARN, if present.
+    #[must_use]
+    pub fn account_id(&self) -> Option<&AccountIdentifier> {
+        self.account_id.as_ref()
+    }
+
+    /// Return `true` if this ARN names a resource owned by AWS itself, such as a managed IAM
+    /// policy, where the account component is the `aws` service identifier rather than a
+    /// numeric account, else `false`.
+    ///
+    /// ```rust
+    /// use aws_arn::ResourceName;
+    /// use std::str::FromStr;
+    ///
+    /// let arn = ResourceName::from_str("arn:aws:iam::aws:policy/ReadOnlyAccess").unwrap();
+    /// assert!(arn.is_aws_managed());
+    /// ```
+    #[must_use]
+    pub fn is_aws_managed(&self) -> bool {
+        matches!(
+            &self.account_id,
+            Some(AccountIdentifier::Service(id)) if &**id == AWS_MANAGED_ACCOUNT
+        )
+    }
+
+    /// Return the resource component of this ARN.
+    #[must_use]
+    pub fn resource(&self) -> &ResourceIdentifier {
+        &self.resource
+    }
+
+    /// Return the service namespace of this ARN, without allocating.
+    #[must_use]
+    pub fn service_namespace(&self) -> &str {
+        self.service.as_str()
+    }
+
+    /// Return `true` if either the account or resource components contain wildcard
+    /// characters, else `false`. Such a value is a pattern, as used in policy documents,
+    /// rather than the name of a concrete resource.
+    #[must_use]
+    pub fn has_wildcards(&self) -> bool {
+        self.account_id
+            .as_ref()
+            .is_some_and(AccountIdentifier::has_wildcards)
+            || self.resource.has_wildcards()
+    }
+
+    /// Return `true` if this ARN contains no wildcards and includes both a region and an
+    /// account, else `false`.
+    #[must_use]
+    pub fn is_fully_qualified(&self) -> bool {
+        !self.has_wildcards() && self.region.is_some() && self.account_id.is_some()
+    }
+
     /// Return `true` if the identifier contains variables of the form
     /// `${name}`, else `false`.
+    #[must_use]
     pub fn has_variables(&self) -> bool {
         self.resource.has_variables()
     }
 
+    /// Validate the components of this `ResourceName` against each other. This checks
+    /// that global services have no region (other than a global pseudo-region such as
+    /// `aws-global`), that regional services do, that the account identifier is well-formed,
+    /// that the resource is present and valid, and that the whole ARN is no longer than the
+    /// 2048 characters AWS allows.
+    ///
+    /// Wildcards are allowed in the account, as they are in the `Resource` element of a
+    /// policy; use [`ResourceName::validate_principal`] for an ARN used as a principal.
+    ///
+    /// ```rust
+    /// use aws_arn::{ArnError, ResourceName};
+    /// use std::str::FromStr;
+    ///
+    /// let arn = ResourceName::from_str("arn:aws:iam:us-east-1:123456789012:user/Bob").unwrap();
+    /// assert_eq!(arn.validate(), Err(ArnError::RegionNotAllowed("iam".to_string())));
+    /// ```
+    pub fn validate(&self) -> ArnResult<()> {
+        let len = self.char_len();
+        if len > MAX_LENGTH {
+            return Err(ArnError::TooLong(len));
+        }
+        match &self.region {
+            Some(region) if self.service.is_global() && !region.is_global() => {
+                return Err(ArnError::RegionNotAllowed(self.service.to_string()));
+            }
+            None if self.service.is_regional() => {
+                return Err(ArnError::MissingRegion);
+            }
+            _ => {}
+        }
+        match &self.account_id {
+            Some(AccountIdentifier::Account(account_id)) if !AccountId::is_valid(account_id) => {
+                return Err(ArnError::InvalidAccountId(account_id.to_string()));
+            }
+            Some(AccountIdentifier::Service(service_id)) if !Identifier::is_valid(service_id) => {
+                return Err(ArnError::InvalidAccountId(service_id.to_string()));
+            }
+            _ => {}
+        }
+        if self.service.requires_resource() && self.resource.is_empty() {
+            Err(ArnError::MissingResource)
+        } else if !self.resource.is_empty() && !ResourceIdentifier::is_valid(&self.resource) {
+            Err(ArnError::InvalidResource(self.resource.to_string()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Validate this `ResourceName` for use in the `Principal` element of a policy, as
+    /// `validate` does, and additionally check that the account is not a wildcard. Unlike the
+    /// `Resource` element, AWS does not allow wildcards in principal ARNs.
+    ///
+    /// ```rust
+    /// use aws_arn::{ArnError, ResourceName};
+    /// use std::str::FromStr;
+    ///
+    /// let arn = ResourceName::from_str("arn:aws:iam::*:role/Admin").unwrap();
+    /// assert_eq!(arn.validate(), Ok(()));
+    /// assert_eq!(
+    ///     arn.validate_principal(),
+    ///     Err(ArnError::AccountIdWildcardNotAllowed("iam".to_string()))
+    /// );
+    /// ```
+    pub fn validate_principal(&self) -> ArnResult<()> {
+        self.validate()?;
+        match &self.account_id {
+            Some(account_id) if account_id.has_wildcards() => Err(
+                ArnError::AccountIdWildcardNotAllowed(self.service.to_string()),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Validate this `ResourceName` against a user-provided set of rules. If the rule set has
+    /// no rule for this service the name is considered valid.
+    pub fn validate_against(&self, rules: &ResourceRuleSet) -> ArnResult<()> {
+        let Some(rule) = rules.rule_for(&self.service) else {
+            return Ok(());
+        };
+        if rule.require_region && self.region.is_none() {
+            Err(ArnError::MissingRegion)
+        } else if rule.require_account && self.account_id.is_none() {
+            Err(ArnError::MissingAccountId)
+        } else if !rule.resource_types.is_empty()
+            && !rule
+                .resource_types
+                .iter()
+                .any(|prefix| self.resource.starts_with(prefix.as_str()))
+        {
+            Err(ArnError::InvalidResource(self.resource.to_string()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Expand this ARN, used as a template, into one ARN per account in `accounts`. The
+    /// account component is replaced if it is a wildcard, such as `*`, or the variable
+    /// `${account}`, and any `${account}` variable in the resource component is replaced; other
+    /// variables are left as-is. It is an error if there is nothing to replace.
+    ///
+    /// ```rust
+    /// use aws_arn::{AccountId, ResourceName};
+    /// use std::str::FromStr;
+    ///
+    /// let template = ResourceName::from_str("arn:aws:iam::*:role/deploy-${account}").unwrap();
+    /// let accounts = [AccountId::from_str("111111111111").unwrap()];
+    /// let arns = template.expand_accounts(&accounts).unwrap();
+    /// assert_eq!(arns[0].to_string(), "arn:aws:iam::111111111111:role/deploy-111111111111");
+    /// ```
+    pub fn expand_accounts(&self, accounts: &[AccountId]) -> ArnResult<Vec<ResourceName>> {
+        let account_placeholder = self.account_id.as_ref().is_some_and(|account_id| {
+            account_id.has_wildcards() || &**account_id == ACCOUNT_VARIABLE
+        });
+        let resource_placeholder = self.resource.contains(ACCOUNT_VARIABLE);
+        if !account_placeholder && !resource_placeholder {
+            return Err(ArnError::MissingAccountPlaceholder);
+        }
+        accounts
+            .iter()
+            .map(|account| {
+                let mut arn = self.clone();
+                if account_placeholder {
+                    arn = arn.with_account(Some(AccountIdentifier::Account(account.clone())));
+                }
+                if resource_placeholder {
+                    let context = HashMap::from([("account".to_string(), account.to_string())]);
+                    arn = arn.replace_variables(&context)?;
+                }
+                Ok(arn)
+            })
+            .collect()
+    }
+
     /// Replace any variables in the string with values from the context,
     /// returning a new value if the replacements result in a legal identifier
     /// string.
@@ -252,6 +1282,7 @@ impl ResourceName {
     {
         Ok(Self {
             resource: self.resource.replace_variables(context)?,
+            source: None,
             ..self.clone()
         })
     }
@@ -263,6 +1294,11 @@ doc_comment::doctest!("../README.md");
 #[cfg(feature = "builders")]
 pub mod builder;
 
+#[cfg(feature = "proptest")]
+mod arbitrary;
+
+mod macros;
+
 #[doc(hidden)]
 mod error;
-pub use error::{ArnError, ArnResult};
+pub use error::{ArnError, ArnErrorCode, ArnResult};
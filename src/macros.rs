@@ -0,0 +1,33 @@
+//! Provides the `arn!` macro for literal ARN values.
+
+/// Parse a string literal as a [`ResourceName`](crate::ResourceName), returning a
+/// `&'static ResourceName`.
+///
+/// The literal is parsed once, on first use, and the result is shared by all later uses of
+/// the same macro invocation. An invalid literal panics on first use with a message that
+/// includes the literal and the parse error.
+///
+/// ```rust
+/// use aws_arn::{arn, ResourceName, Service};
+///
+/// let bucket: &'static ResourceName = arn!("arn:aws:s3:::my-bucket");
+/// assert_eq!(bucket.service, Service::S3);
+/// ```
+///
+/// ```rust,should_panic
+/// use aws_arn::arn;
+///
+/// // panics: invalid ARN literal "arn:aws:not-a-service:::my-bucket"
+/// let _ = arn!("arn:aws:not-a-service:::my-bucket");
+/// ```
+#[macro_export]
+macro_rules! arn {
+    ($arn:literal) => {{
+        static ARN: ::std::sync::LazyLock<$crate::ResourceName> =
+            ::std::sync::LazyLock::new(|| {
+                <$crate::ResourceName as ::std::str::FromStr>::from_str($arn)
+                    .unwrap_or_else(|e| panic!("invalid ARN literal {:?}: {}", $arn, e))
+            });
+        &*ARN
+    }};
+}
@@ -0,0 +1,398 @@
+//! Provides types for ARNs that may contain wildcards, as used in policy documents.
+
+use crate::types::{CHAR_WILD_ANY, CHAR_WILD_ONE};
+use crate::{
+    in_component, parse_resource, split_complete, AccountIdentifier, ArnError, Components,
+    Partition, Region, ResourceName, Service, ARN_PREFIX,
+};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// A `ResourceNamePattern` is a [`ResourceName`] that may contain wildcard characters
+/// (`*` and `?`) in the account or resource components, as commonly found in the
+/// `Resource` element of an IAM policy.
+///
+/// Keeping patterns distinct from concrete names means that a comparison between the
+/// two is always explicit; a pattern is only equal to a `ResourceName` when it contains
+/// no wildcards and all components are equal.
+///
+/// ```rust
+/// use aws_arn::{ResourceName, ResourceNamePattern};
+/// use std::str::FromStr;
+///
+/// let arn = ResourceName::from_str("arn:aws:s3:::my-bucket/logs").unwrap();
+///
+/// let concrete = ResourceNamePattern::from_str("arn:aws:s3:::my-bucket/logs").unwrap();
+/// assert!(concrete == arn);
+///
+/// let wildcard = ResourceNamePattern::from_str("arn:aws:s3:::my-bucket/*").unwrap();
+/// assert!(wildcard != arn);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceNamePattern(ResourceName);
+
+impl Display for ResourceNamePattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for ResourceNamePattern {
+    type Err = ArnError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(ResourceName::from_str(s)?))
+    }
+}
+
+impl From<ResourceName> for ResourceNamePattern {
+    fn from(v: ResourceName) -> Self {
+        Self(v)
+    }
+}
+
+impl From<ResourceNamePattern> for ResourceName {
+    fn from(v: ResourceNamePattern) -> Self {
+        v.0
+    }
+}
+
+impl Deref for ResourceNamePattern {
+    type Target = ResourceName;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl PartialEq<ResourceName> for ResourceNamePattern {
+    fn eq(&self, other: &ResourceName) -> bool {
+        self.is_concrete() && self.0 == *other
+    }
+}
+
+impl PartialEq<ResourceNamePattern> for ResourceName {
+    fn eq(&self, other: &ResourceNamePattern) -> bool {
+        other == self
+    }
+}
+
+/// Return the contiguous sub-slice of `sorted` whose elements match the leading, literal,
+/// components of `prefix`, using binary search.
+///
+/// The prefix is made up of the pattern's partition, service, and region, then its account
+/// and resource in turn until a component contains a wildcard. Where the resource contains a
+/// wildcard only the text before the first wildcard character is used. Wildcards after this
+/// point are not evaluated, so the result contains every candidate that shares the literal
+/// prefix. An empty region or account in the pattern only matches an empty component.
+///
+/// The slice `sorted` **must** be sorted according to the `Ord` implementation of
+/// `ResourceName`, otherwise the result is unspecified.
+///
+/// ```rust
+/// use aws_arn::{find_by_prefix, ResourceName, ResourceNamePattern};
+/// use std::str::FromStr;
+///
+/// let mut arns: Vec<ResourceName> = [
+///     "arn:aws:s3:::my-bucket/a",
+///     "arn:aws:lambda:us-east-1:123456789012:function:f",
+///     "arn:aws:s3:::my-bucket/b",
+///     "arn:aws:s3:::other-bucket/a",
+/// ]
+/// .iter()
+/// .map(|s| ResourceName::from_str(s).unwrap())
+/// .collect();
+/// arns.sort();
+///
+/// let prefix = ResourceNamePattern::from_str("arn:aws:s3:::my-bucket/*").unwrap();
+/// assert_eq!(find_by_prefix(&arns, &prefix).len(), 2);
+/// ```
+pub fn find_by_prefix<'a>(
+    sorted: &'a [ResourceName],
+    prefix: &ResourceNamePattern,
+) -> &'a [ResourceName] {
+    let start = sorted.partition_point(|arn| prefix.compare_prefix(arn) == Ordering::Less);
+    let end = sorted.partition_point(|arn| prefix.compare_prefix(arn) != Ordering::Greater);
+    &sorted[start..end]
+}
+
+impl ResourceNamePattern {
+    /// Construct a new pattern from the provided `ResourceName`.
+    pub fn new(arn: ResourceName) -> Self {
+        Self(arn)
+    }
+
+    /// Return `true` if neither the account nor the resource components contain
+    /// wildcard characters, else `false`.
+    pub fn is_concrete(&self) -> bool {
+        !self.0.has_wildcards()
+    }
+
+    // Compare `arn` against the literal prefix of this pattern, returning `Equal` if it falls
+    // within the range of names sharing the prefix.
+    fn compare_prefix(&self, arn: &ResourceName) -> Ordering {
+        let pattern = &self.0;
+        let ordering = arn
+            .partition
+            .cmp(&pattern.partition)
+            .then_with(|| arn.service.cmp(&pattern.service));
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+
+        let ordering = arn.region.cmp(&pattern.region);
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+
+        match &pattern.account_id {
+            Some(account_id) if account_id.has_wildcards() => return Ordering::Equal,
+            account_id => {
+                let ordering = arn.account_id.as_ref().cmp(&account_id.as_ref());
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+
+        let resource: &str = &pattern.resource;
+        match resource.find([CHAR_WILD_ANY, CHAR_WILD_ONE]) {
+            None => arn.resource.deref().cmp(resource),
+            Some(index) => {
+                let literal = &resource[..index];
+                if arn.resource.starts_with(literal) {
+                    Ordering::Equal
+                } else {
+                    arn.resource.deref().cmp(literal)
+                }
+            }
+        }
+    }
+}
+
+/// An `ArnPattern` is an ARN that may contain the wildcard characters `*` and `?` in the
+/// region, account, or resource components, as found in the `Resource` element of an IAM
+/// policy. The pattern is parsed once, so it may be matched against many names cheaply.
+///
+/// This is separate from [`ResourceNamePattern`], which wraps a `ResourceName` so that it can be
+/// ordered and compared with concrete names, as [`find_by_prefix`] requires; it therefore cannot
+/// hold a wildcard region, and has to find the wildcards in each component again whenever it is
+/// used. An `ArnPattern` is never compared for equality with a [`ResourceName`]; whether a name
+/// is covered by the pattern is only answered by [`ArnPattern::matches`]. A
+/// `ResourceNamePattern` may be converted into an `ArnPattern` to match against it.
+///
+/// ```rust
+/// use aws_arn::{ArnPattern, ResourceName};
+/// use std::str::FromStr;
+///
+/// let pattern = ArnPattern::from_str("arn:aws:sqs:us-*:*:queue-?").unwrap();
+/// let arn = ResourceName::from_str("arn:aws:sqs:us-east-1:123456789012:queue-1").unwrap();
+/// assert!(pattern.matches(&arn));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArnPattern {
+    partition: Partition,
+    service: Service,
+    region: Glob,
+    account: Glob,
+    resource: Glob,
+}
+
+impl Display for ArnPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}:{}:{}:{}",
+            ARN_PREFIX, self.partition, self.service, self.region, self.account, self.resource,
+        )
+    }
+}
+
+impl FromStr for ArnPattern {
+    type Err = ArnError;
+
+    /// Parse a pattern with the same format as a `ResourceName`. Components without wildcards
+    /// must be valid as they are for a `ResourceName`, and a region with wildcards may only
+    /// contain the characters found in region names.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = Components::new(split_complete(s)?)?;
+
+        let partition = Partition::from_str(parts.next()?).map_err(in_component(1))?;
+        let service = Service::from_str(parts.next()?).map_err(in_component(2))?;
+        let region = match parts.next()? {
+            "" => Glob::new(""),
+            region if region.contains([CHAR_WILD_ANY, CHAR_WILD_ONE]) => {
+                if !region.chars().all(|c| {
+                    c.is_ascii_alphanumeric()
+                        || c == '-'
+                        || c == CHAR_WILD_ANY
+                        || c == CHAR_WILD_ONE
+                }) {
+                    return Err(in_component(3)(ArnError::InvalidRegion(region.to_string())));
+                }
+                // Region names are parsed ignoring case, so match them ignoring case too.
+                Glob::new(&region.to_ascii_lowercase())
+            }
+            region => Glob::new(Region::from_str(region).map_err(in_component(3))?.as_ref()),
+        };
+        let account = parts.next()?;
+        if !account.is_empty() {
+            let _ = AccountIdentifier::from_str(account).map_err(in_component(4))?;
+        }
+        let resource = parse_resource(parts.next()?)?;
+
+        Ok(Self {
+            partition,
+            service,
+            region,
+            account: Glob::new(account),
+            resource: Glob::new(&resource),
+        })
+    }
+}
+
+impl From<&ResourceName> for ArnPattern {
+    fn from(v: &ResourceName) -> Self {
+        Self {
+            partition: v.partition.clone(),
+            service: v.service.clone(),
+            region: Glob::new(v.region.as_ref().map_or("", AsRef::as_ref)),
+            account: Glob::new(v.account_id.as_deref().unwrap_or_default()),
+            resource: Glob::new(&v.resource),
+        }
+    }
+}
+
+impl From<&ResourceNamePattern> for ArnPattern {
+    fn from(v: &ResourceNamePattern) -> Self {
+        Self::from(&v.0)
+    }
+}
+
+impl ArnPattern {
+    /// Return `true` if none of the region, account, or resource components contain wildcard
+    /// characters, else `false`.
+    pub fn is_concrete(&self) -> bool {
+        !(self.region.has_wildcards()
+            || self.account.has_wildcards()
+            || self.resource.has_wildcards())
+    }
+
+    /// Return `true` if `arn` matches this pattern, else `false`.
+    ///
+    /// The partition and service must be equal. The region, account, and resource components
+    /// are matched using glob semantics, as in IAM policies, where `*` matches any sequence of
+    /// characters, including separators, and `?` matches any single character. An absent
+    /// region or account is matched as the empty string, so it is matched by an empty
+    /// component or by `*`.
+    ///
+    /// ```rust
+    /// use aws_arn::{ArnPattern, ResourceName};
+    /// use std::str::FromStr;
+    ///
+    /// let pattern = ArnPattern::from_str("arn:aws:s3:::my-bucket/*").unwrap();
+    /// let arn = ResourceName::from_str("arn:aws:s3:::my-bucket/logs/today").unwrap();
+    /// assert!(pattern.matches(&arn));
+    /// ```
+    pub fn matches(&self, arn: &ResourceName) -> bool {
+        self.partition == arn.partition
+            && self.service == arn.service
+            && self
+                .region
+                .matches(arn.region.as_ref().map_or("", AsRef::as_ref))
+            && self
+                .account
+                .matches(arn.account_id.as_deref().unwrap_or_default())
+            && self.resource.matches(&arn.resource)
+    }
+}
+
+// A component of a pattern, split into literal text and wildcards.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Glob(Vec<GlobToken>);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum GlobToken {
+    Literal(Box<str>),
+    AnyOne,
+    AnyMany,
+}
+
+impl Display for Glob {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for token in &self.0 {
+            match token {
+                GlobToken::Literal(s) => f.write_str(s)?,
+                GlobToken::AnyOne => write!(f, "{}", CHAR_WILD_ONE)?,
+                GlobToken::AnyMany => write!(f, "{}", CHAR_WILD_ANY)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Glob {
+    pub(crate) fn new(pattern: &str) -> Self {
+        let mut tokens = Vec::new();
+        let mut rest = pattern;
+        while let Some(index) = rest.find([CHAR_WILD_ANY, CHAR_WILD_ONE]) {
+            if index > 0 {
+                tokens.push(GlobToken::Literal(rest[..index].into()));
+            }
+            tokens.push(if rest[index..].starts_with(CHAR_WILD_ANY) {
+                GlobToken::AnyMany
+            } else {
+                GlobToken::AnyOne
+            });
+            rest = &rest[index + 1..];
+        }
+        if !rest.is_empty() {
+            tokens.push(GlobToken::Literal(rest.into()));
+        }
+        Self(tokens)
+    }
+
+    fn has_wildcards(&self) -> bool {
+        self.0.iter().any(|t| !matches!(t, GlobToken::Literal(_)))
+    }
+
+    // Match `value` against this pattern, where `*` matches any sequence of characters and `?`
+    // matches any single character. On a mismatch, backtrack to the most recent `*` and let it
+    // consume one more character.
+    pub(crate) fn matches(&self, value: &str) -> bool {
+        let (mut t, mut v) = (0, 0);
+        let mut backtrack: Option<(usize, usize)> = None;
+        loop {
+            let matched = match self.0.get(t) {
+                None if v == value.len() => return true,
+                None => None,
+                Some(GlobToken::AnyMany) => {
+                    backtrack = Some((t, v));
+                    Some(v)
+                }
+                Some(GlobToken::AnyOne) => value[v..].chars().next().map(|c| v + c.len_utf8()),
+                Some(GlobToken::Literal(literal)) => value[v..]
+                    .starts_with(&**literal)
+                    .then(|| v + literal.len()),
+            };
+            match (matched, backtrack) {
+                (Some(next), _) => {
+                    t += 1;
+                    v = next;
+                }
+                (None, Some((star, consumed))) => match value[consumed..].chars().next() {
+                    Some(c) => {
+                        let next = consumed + c.len_utf8();
+                        backtrack = Some((star, next));
+                        t = star + 1;
+                        v = next;
+                    }
+                    None => return false,
+                },
+                (None, None) => return false,
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Conversion between lists of [`ResourceName`]s and the `Resource` element of an IAM policy.
+//!
+//! IAM accepts the `Resource` element either as a single string or as an array of strings,
+//! see the [AWS documentation](https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_elements_resource.html).
+
+use crate::{ArnError, ArnResult, ResourceName};
+use serde_json::Value;
+use std::str::FromStr;
+
+/// Convert a list of ARNs into a value suitable for the `Resource` element of an IAM policy.
+/// A single ARN is returned as a JSON string, any other number of ARNs as a JSON array of
+/// strings.
+///
+/// ```rust
+/// use aws_arn::{to_policy_resource_json, ResourceName};
+/// use std::str::FromStr;
+///
+/// let arn = ResourceName::from_str("arn:aws:s3:::my-bucket/*").unwrap();
+/// assert_eq!(
+///     to_policy_resource_json(&[arn]),
+///     serde_json::Value::String("arn:aws:s3:::my-bucket/*".to_string())
+/// );
+/// ```
+pub fn to_policy_resource_json(arns: &[ResourceName]) -> Value {
+    match arns {
+        [arn] => Value::String(arn.to_string()),
+        arns => Value::Array(
+            arns.iter()
+                .map(|arn| Value::String(arn.to_string()))
+                .collect(),
+        ),
+    }
+}
+
+/// Parse the `Resource` element of an IAM policy, either a single string or an array of
+/// strings, into a list of ARNs.
+pub fn from_policy_resource_json(value: &Value) -> ArnResult<Vec<ResourceName>> {
+    match value {
+        Value::String(s) => Ok(vec![ResourceName::from_str(s)?]),
+        Value::Array(values) => values
+            .iter()
+            .map(|value| match value {
+                Value::String(s) => ResourceName::from_str(s),
+                _ => Err(ArnError::InvalidPolicyResource(value.to_string())),
+            })
+            .collect(),
+        _ => Err(ArnError::InvalidPolicyResource(value.to_string())),
+    }
+}
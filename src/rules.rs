@@ -0,0 +1,59 @@
+//! User-defined, per-service, rules for the shape of ARNs.
+//!
+//! A [`ResourceRuleSet`] allows a team to describe the ARNs it expects for each service, and
+//! extend those rules without waiting for a release of this crate. With the `serde` feature
+//! enabled a rule set can be loaded from JSON, as in the following example.
+//!
+//! ```json
+//! {
+//!   "services": {
+//!     "lambda": {
+//!       "resource_types": ["function:", "layer:"],
+//!       "require_region": true,
+//!       "require_account": true
+//!     },
+//!     "s3": {}
+//!   }
+//! }
+//! ```
+
+use crate::Service;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The rules for ARNs in a single service.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ServiceRule {
+    /// The prefixes that a resource must start with, for example `"function:"` or `"user/"`.
+    /// If empty, any resource is allowed.
+    pub resource_types: Vec<String>,
+    /// If `true` the ARN must include a region.
+    pub require_region: bool,
+    /// If `true` the ARN must include an account.
+    pub require_account: bool,
+}
+
+/// A set of [`ServiceRule`]s, keyed by service. Services with no rule are not constrained.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ResourceRuleSet {
+    /// The rule for each service.
+    pub services: HashMap<Service, ServiceRule>,
+}
+
+impl ResourceRuleSet {
+    /// Load a rule set from its JSON representation.
+    #[cfg(feature = "serde")]
+    pub fn from_json(s: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(s)
+    }
+
+    /// Return the rule for the provided service, if one exists.
+    pub fn rule_for(&self, service: &Service) -> Option<&ServiceRule> {
+        self.services.get(service)
+    }
+}
@@ -0,0 +1,118 @@
+//! Line-by-line validation of ARNs read from a stream.
+//!
+//! This is intended for very large inputs; each line is parsed and the result written
+//! immediately to the output so that no results are held in memory.
+
+use crate::{ArnResult, ResourceName};
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+
+const COMMENT_PREFIX: &str = "#";
+
+/// The format used by [`validate_stream`] to write each result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// One line per input ARN, with the line number, `ok` or `error`, and the ARN or error
+    /// message separated by tab characters.
+    Text,
+    /// One JSON object per input ARN, with `line`, `input`, and `valid` fields and an `error`
+    /// field if the ARN is not valid. This requires the `serde` feature; without it
+    /// [`validate_stream`] returns an error of kind [`std::io::ErrorKind::Unsupported`] before
+    /// reading any input.
+    JsonLines,
+}
+
+/// The counts of valid and invalid ARNs seen by [`validate_stream`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StreamSummary {
+    /// The number of lines that parsed as a valid ARN.
+    pub valid: usize,
+    /// The number of lines that did not parse as a valid ARN.
+    pub invalid: usize,
+}
+
+/// Read ARNs from `input`, one per line, and write the result of parsing each one to
+/// `output` in the requested format. Blank lines and lines starting with `#` are skipped.
+/// Line numbers in the output are 1-based and refer to the line in `input`. Lines may end
+/// with either `\n` or `\r\n`.
+///
+/// ```rust
+/// use aws_arn::{validate_stream, OutputFormat, StreamSummary};
+///
+/// let input = "arn:aws:s3:::my-bucket\nnot-an-arn\n";
+/// let mut output = Vec::new();
+///
+/// let summary = validate_stream(input.as_bytes(), &mut output, OutputFormat::Text).unwrap();
+/// assert_eq!(summary, StreamSummary { valid: 1, invalid: 1 });
+/// ```
+pub fn validate_stream<R: BufRead, W: Write>(
+    input: R,
+    mut output: W,
+    format: OutputFormat,
+) -> std::io::Result<StreamSummary> {
+    if format == OutputFormat::JsonLines && !cfg!(feature = "serde") {
+        return Err(json_lines_unsupported());
+    }
+    let mut summary = StreamSummary::default();
+    for (index, line) in input.lines().enumerate() {
+        let line = line?;
+        if is_ignored_line(&line) {
+            continue;
+        }
+        let result = ResourceName::from_str(&line);
+        if result.is_ok() {
+            summary.valid += 1;
+        } else {
+            summary.invalid += 1;
+        }
+        match format {
+            OutputFormat::Text => match result {
+                Ok(arn) => writeln!(output, "{}\tok\t{}", index + 1, arn)?,
+                Err(e) => writeln!(output, "{}\terror\t{}", index + 1, e)?,
+            },
+            OutputFormat::JsonLines => write_json_line(&mut output, index + 1, &line, result)?,
+        }
+    }
+    output.flush()?;
+    Ok(summary)
+}
+
+#[cfg(feature = "serde")]
+fn write_json_line<W: Write>(
+    output: &mut W,
+    line_number: usize,
+    line: &str,
+    result: ArnResult<ResourceName>,
+) -> std::io::Result<()> {
+    let mut value = serde_json::json!({
+        "line": line_number,
+        "input": line,
+        "valid": result.is_ok(),
+    });
+    if let Err(e) = result {
+        value["error"] = serde_json::Value::String(e.to_string());
+    }
+    writeln!(output, "{value}")
+}
+
+#[cfg(not(feature = "serde"))]
+fn write_json_line<W: Write>(
+    _output: &mut W,
+    _line_number: usize,
+    _line: &str,
+    _result: ArnResult<ResourceName>,
+) -> std::io::Result<()> {
+    Err(json_lines_unsupported())
+}
+
+fn json_lines_unsupported() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "JSON lines output requires the `serde` feature",
+    )
+}
+
+// Blank lines, and lines starting with the comment prefix, are not parsed as ARNs.
+pub(crate) fn is_ignored_line(line: &str) -> bool {
+    line.is_empty() || line.starts_with(COMMENT_PREFIX)
+}
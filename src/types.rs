@@ -5,9 +5,11 @@ mod region;
 mod service;
 
 pub use identifier::{
-    AccountId, AccountIdentifier, Identifier, IdentifierLike, ResourceIdentifier,
+    AccountId, AccountIdentifier, Identifier, IdentifierLike, ResourceIdentifier, ResourceShape,
+    Substitution,
 };
-pub(crate) use identifier::{ARN_PREFIX, PART_SEPARATOR, REQUIRED_COMPONENT_COUNT};
+pub use identifier::{ARN_PREFIX, MAX_IDENTIFIER_LENGTH, REQUIRED_COMPONENT_COUNT};
+pub(crate) use identifier::{CHAR_WILD_ANY, CHAR_WILD_ONE, PART_SEPARATOR, PATH_SEPARATOR};
 pub use partition::Partition;
-pub use region::Region;
-pub use service::Service;
+pub use region::{Continent, Region};
+pub use service::{Service, ServiceCategory};
@@ -1,8 +1,7 @@
 //! Primitives for low-level identifiers that comprise Amazon Resource Names.
 use regex::{Captures, Regex};
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 use std::str::FromStr;
@@ -10,20 +9,108 @@ use std::sync::LazyLock;
 
 use crate::{ArnError, ArnResult};
 
-pub(crate) const ARN_PREFIX: &str = "arn";
+/// The literal value of the first component of every ARN.
+///
+/// Together with [`REQUIRED_COMPONENT_COUNT`] this may be used to cheaply reject strings that
+/// are obviously not ARNs before calling the full parser.
+///
+/// ```rust
+/// use aws_arn::{ARN_PREFIX, REQUIRED_COMPONENT_COUNT};
+///
+/// fn might_be_arn(s: &str) -> bool {
+///     s.starts_with(&format!("{}:", ARN_PREFIX))
+///         && s.splitn(REQUIRED_COMPONENT_COUNT, ':').count() == REQUIRED_COMPONENT_COUNT
+/// }
+///
+/// assert!(might_be_arn("arn:aws:s3:::my-bucket"));
+/// assert!(!might_be_arn("arn:aws:s3"));
+/// assert!(!might_be_arn("not-an-arn"));
+/// ```
+pub const ARN_PREFIX: &str = "arn";
 
 pub(crate) const PART_SEPARATOR: char = ':';
-const PATH_SEPARATOR: char = '/';
+pub(crate) const PATH_SEPARATOR: char = '/';
 
 const STRING_WILD_ANY: &str = "*";
 
 const CHAR_ASCII_START: char = '\u{1F}';
 const CHAR_ASCII_END: char = '\u{7F}';
 const CHAR_SPACE: char = ' ';
-const CHAR_WILD_ONE: char = '?';
-const CHAR_WILD_ANY: char = '*';
+pub(crate) const CHAR_WILD_ONE: char = '?';
+pub(crate) const CHAR_WILD_ANY: char = '*';
+
+/// The number of ':' separated components in every ARN, including the [`ARN_PREFIX`]; the
+/// final resource component may itself contain ':' characters.
+pub const REQUIRED_COMPONENT_COUNT: usize = 6;
+
+/// The maximum length, in bytes, of a valid [`Identifier`]. AWS does not publish a single limit;
+/// this conservative bound exists to reject garbage input rather than any real identifier.
+pub const MAX_IDENTIFIER_LENGTH: usize = 1024;
+
+/// The inner storage of the identifier newtypes. With the `compact` feature enabled this is a
+/// small-string-optimized type, so that most components are stored inline; in either case the
+/// newtypes `Deref` to `str`, so the public API is the same.
+#[cfg(not(feature = "compact"))]
+type IdentifierString = String;
+#[cfg(feature = "compact")]
+type IdentifierString = compact_str::CompactString;
+
+#[cfg(not(feature = "compact"))]
+fn into_string(s: IdentifierString) -> String {
+    s
+}
+
+#[cfg(feature = "compact")]
+fn into_string(s: IdentifierString) -> String {
+    s.into_string()
+}
 
-pub(crate) const REQUIRED_COMPONENT_COUNT: usize = 6;
+#[cfg(not(feature = "compact"))]
+fn from_string(s: String) -> IdentifierString {
+    s
+}
+
+#[cfg(feature = "compact")]
+fn from_string(s: String) -> IdentifierString {
+    IdentifierString::from(s)
+}
+
+// Implement `TryFrom<String>` for an identifier newtype, moving the string into it when valid
+// rather than copying it as `FromStr` must, and otherwise returning the error built by `$error`.
+macro_rules! try_from_string {
+    ($type:ty, $error:ident) => {
+        impl TryFrom<String> for $type {
+            type Error = ArnError;
+
+            fn try_from(s: String) -> Result<Self, Self::Error> {
+                if <$type>::is_valid(&s) {
+                    Ok(Self(from_string(s)))
+                } else {
+                    Err($error(&s))
+                }
+            }
+        }
+    };
+}
+
+fn invalid_identifier(s: &str) -> ArnError {
+    ArnError::InvalidIdentifier(s.to_string())
+}
+
+fn invalid_account_id(s: &str) -> ArnError {
+    ArnError::InvalidAccountId(s.to_string())
+}
+
+fn invalid_resource(s: &str) -> ArnError {
+    match s.chars().find(|c| c.is_control()) {
+        Some(c) => ArnError::InvalidResourceCharacter(c),
+        None => ArnError::InvalidResource(s.to_string()),
+    }
+}
+
+/// A variable found by [`ResourceIdentifier::replace_variables_logged`], as its name and the
+/// value it was replaced with, or `None` if the context had no value for it.
+pub type Substitution = (String, Option<String>);
 
 static REGEX_VARIABLE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"\$\{([^$}]+)\}").expect("failed to initialize regex"));
@@ -73,9 +160,12 @@ where
 /// A string value that is used to capture the partition, service, and region components
 /// of an ResourceName. These are ASCII only, may not include control characters, spaces, '/', or ':'.
 ///
-#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct Identifier(String);
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_with::DeserializeFromStr, serde_with::SerializeDisplay)
+)]
+pub struct Identifier(IdentifierString);
 
 impl Display for Identifier {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -88,19 +178,21 @@ impl FromStr for Identifier {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if Self::is_valid(s) {
-            Ok(Self(s.to_string()))
+            Ok(Self(s.into()))
         } else {
-            Err(ArnError::InvalidIdentifier(s.to_string()))
+            Err(invalid_identifier(s))
         }
     }
 }
 
 impl From<Identifier> for String {
     fn from(v: Identifier) -> Self {
-        v.0
+        into_string(v.0)
     }
 }
 
+try_from_string!(Identifier, invalid_identifier);
+
 impl Deref for Identifier {
     type Target = str;
 
@@ -109,13 +201,29 @@ impl Deref for Identifier {
     }
 }
 
+impl Identifier {
+    /// Construct a new `Identifier`, returning an error if `s` is not a valid identifier. This
+    /// is equivalent to `Identifier::from_str`.
+    ///
+    /// ```rust
+    /// use aws_arn::Identifier;
+    ///
+    /// assert!(Identifier::new("my-function").is_ok());
+    /// assert!(Identifier::new("a/b").is_err());
+    /// ```
+    pub fn new(s: &str) -> ArnResult<Self> {
+        Self::from_str(s)
+    }
+}
+
 impl IdentifierLike for Identifier {
     fn new_unchecked(s: &str) -> Self {
-        Self(s.to_string())
+        Self(s.into())
     }
 
     fn is_valid(s: &str) -> bool {
         !s.is_empty()
+            && s.len() <= MAX_IDENTIFIER_LENGTH
             && s.chars().all(|c| {
                 c > CHAR_ASCII_START
                     && c < CHAR_ASCII_END
@@ -127,8 +235,11 @@ impl IdentifierLike for Identifier {
 }
 
 /// Possible ways a [`ResourceName`] can represent the account identifier
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_with::DeserializeFromStr, serde_with::SerializeDisplay)
+)]
 pub enum AccountIdentifier {
     /// Represents a 12-digit identifier for an AWS account ID.
     ///
@@ -149,6 +260,29 @@ impl Display for AccountIdentifier {
     }
 }
 
+impl AccountIdentifier {
+    /// Returns `true` if this account identifier contains any wildcard characters,
+    /// else `false`.
+    #[must_use]
+    pub fn has_wildcards(&self) -> bool {
+        match self {
+            Self::Account(account_id) => account_id.has_wildcards(),
+            Self::Service(service_id) => service_id.has_wildcards(),
+        }
+    }
+}
+
+impl Deref for AccountIdentifier {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Account(account_id) => account_id,
+            Self::Service(service_id) => service_id,
+        }
+    }
+}
+
 impl FromStr for AccountIdentifier {
     type Err = ArnError;
 
@@ -174,14 +308,19 @@ impl From<Identifier> for AccountIdentifier {
     }
 }
 
+const MAX_ACCOUNT_ID: u64 = 999_999_999_999;
+
 static ACCOUNT_ID_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^([0-9]{12}|\*)$").expect("failed to init account ID regex"));
 
 /// A string value that is used to capture the account ID component
 /// of an ResourceName. These are ASCII digits only and a fixed length of 12 characters.
-#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct AccountId(String);
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_with::DeserializeFromStr, serde_with::SerializeDisplay)
+)]
+pub struct AccountId(IdentifierString);
 
 impl Display for AccountId {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -193,15 +332,55 @@ impl FromStr for AccountId {
     type Err = ArnError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::is_valid(s)
-            .then_some(Self(s.to_string()))
-            .ok_or(ArnError::InvalidAccountId(s.to_string()))
+        if Self::is_valid(s) {
+            Ok(Self(s.into()))
+        } else {
+            Err(invalid_account_id(s))
+        }
     }
 }
 
 impl From<AccountId> for String {
     fn from(v: AccountId) -> Self {
-        v.0
+        into_string(v.0)
+    }
+}
+
+try_from_string!(AccountId, invalid_account_id);
+
+impl TryFrom<u64> for AccountId {
+    type Error = ArnError;
+
+    /// Construct an account ID from its numeric value, zero-padding to 12 digits. Values
+    /// that do not fit in 12 digits are rejected.
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        if value > MAX_ACCOUNT_ID {
+            Err(ArnError::InvalidAccountId(value.to_string()))
+        } else {
+            Ok(Self::new_unchecked(&format!("{:012}", value)))
+        }
+    }
+}
+
+impl AccountId {
+    /// Construct a new `AccountId`, returning an error if `s` is not a valid account ID. This
+    /// is equivalent to `AccountId::from_str`.
+    ///
+    /// ```rust
+    /// use aws_arn::AccountId;
+    ///
+    /// assert!(AccountId::new("123456789012").is_ok());
+    /// assert!(AccountId::new("1234").is_err());
+    /// ```
+    pub fn new(s: &str) -> ArnResult<Self> {
+        Self::from_str(s)
+    }
+
+    /// Return the numeric value of this account ID, or `None` if it is a wildcard or
+    /// otherwise not numeric.
+    #[must_use]
+    pub fn as_u64(&self) -> Option<u64> {
+        self.0.parse().ok()
     }
 }
 
@@ -215,7 +394,7 @@ impl Deref for AccountId {
 
 impl IdentifierLike for AccountId {
     fn new_unchecked(s: &str) -> Self {
-        Self(s.to_string())
+        Self(s.into())
     }
 
     fn is_valid(s: &str) -> bool {
@@ -223,8 +402,9 @@ impl IdentifierLike for AccountId {
     }
 }
 
-/// A string value that is used to capture the resource component of an ResourceName. These are ASCII only,
-/// may not include control characters but unlike `Identifier` they may include spaces, '/', and ':'.
+/// A string value that is used to capture the resource component of an ResourceName. Unlike
+/// `Identifier`, which is ASCII only, these may include any Unicode character other than control
+/// characters, including spaces, '/', and ':'; for example S3 object keys may be any UTF-8 string.
 ///
 /// > *The content of this part of the ResourceName varies by service. A resource identifier can be the name
 /// > or ID of the resource (for example, `user/Bob` or `instance/i-1234567890abcdef0`) or a
@@ -238,9 +418,26 @@ impl IdentifierLike for AccountId {
 ///
 /// > *In some circumstances, paths can include a wildcard character, namely an asterisk ('*').*
 ///
-#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct ResourceIdentifier(String);
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_with::DeserializeFromStr, serde_with::SerializeDisplay)
+)]
+pub struct ResourceIdentifier(IdentifierString);
+
+/// The primary shape of a [`ResourceIdentifier`], determined by the separator characters
+/// it contains.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceShape {
+    /// Contains neither path nor qualifier separators, for example `bucket`.
+    Plain,
+    /// Contains only path separators, for example `user/Bob`.
+    Path,
+    /// Contains only qualifier separators, for example `layer:name:3`.
+    Qualified,
+    /// Contains both path and qualifier separators, for example `type/name:qualifier`.
+    Mixed,
+}
 
 impl Display for ResourceIdentifier {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -253,19 +450,21 @@ impl FromStr for ResourceIdentifier {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if Self::is_valid(s) {
-            Ok(Self(s.to_string()))
+            Ok(Self(s.into()))
         } else {
-            Err(ArnError::InvalidResource(s.to_string()))
+            Err(invalid_resource(s))
         }
     }
 }
 
 impl From<ResourceIdentifier> for String {
     fn from(v: ResourceIdentifier) -> Self {
-        v.0
+        into_string(v.0)
     }
 }
 
+try_from_string!(ResourceIdentifier, invalid_resource);
+
 impl From<Identifier> for ResourceIdentifier {
     fn from(v: Identifier) -> Self {
         ResourceIdentifier::new_unchecked(&v.0)
@@ -280,13 +479,24 @@ impl Deref for ResourceIdentifier {
     }
 }
 
+/// Return `true` if the UTF-8 encoded `bytes` contain a character for which `char::is_control`
+/// is `true`, without decoding characters. Control characters are U+0000 to U+001F, U+007F, and
+/// U+0080 to U+009F; the last range is encoded as the lead byte `0xC2` followed by a byte in
+/// `0x80..=0x9F`. As `0xC2` is never a continuation byte, and bytes below `0x80` are always
+/// ASCII, a bytewise scan is exact.
+fn contains_control(bytes: &[u8]) -> bool {
+    bytes.iter().enumerate().any(|(i, &b)| {
+        b < 0x20 || b == 0x7F || (b == 0xC2 && matches!(bytes.get(i + 1), Some(0x80..=0x9F)))
+    })
+}
+
 impl IdentifierLike for ResourceIdentifier {
     fn new_unchecked(s: &str) -> Self {
-        Self(s.to_string())
+        Self(s.into())
     }
 
     fn is_valid(s: &str) -> bool {
-        !s.is_empty() && s.chars().all(|c| c > '\u{1F}' && c < '\u{7F}')
+        !s.is_empty() && !contains_control(s.as_bytes())
     }
 
     fn is_plain(&self) -> bool {
@@ -295,7 +505,21 @@ impl IdentifierLike for ResourceIdentifier {
 }
 
 impl ResourceIdentifier {
+    /// Construct a new `ResourceIdentifier`, returning an error if `s` is not a valid resource
+    /// identifier. This is equivalent to `ResourceIdentifier::from_str`.
+    ///
+    /// ```rust
+    /// use aws_arn::ResourceIdentifier;
+    ///
+    /// assert!(ResourceIdentifier::new("user/Bob").is_ok());
+    /// assert!(ResourceIdentifier::new("").is_err());
+    /// ```
+    pub fn new(s: &str) -> ArnResult<Self> {
+        Self::from_str(s)
+    }
+
     /// Construct a resource identifier, as a path, using the `Identifier` path components.
+    #[must_use]
     pub fn from_id_path(path: &[Identifier]) -> Self {
         Self::new_unchecked(
             &path
@@ -307,6 +531,7 @@ impl ResourceIdentifier {
     }
 
     /// Construct a resource identifier, as a qualified ID, using the `Identifier` path components.
+    #[must_use]
     pub fn from_qualified_id(qualified: &[Identifier]) -> Self {
         Self::new_unchecked(
             &qualified
@@ -318,6 +543,7 @@ impl ResourceIdentifier {
     }
 
     /// Construct a resource identifier, as a path, using the `ResourceIdentifier` path components.
+    #[must_use]
     pub fn from_path(path: &[ResourceIdentifier]) -> Self {
         Self::new_unchecked(
             &path
@@ -329,6 +555,7 @@ impl ResourceIdentifier {
     }
 
     /// Construct a resource identifier, as a qualified ID, using the `ResourceIdentifier` path components.
+    #[must_use]
     pub fn from_qualified(qualified: &[ResourceIdentifier]) -> Self {
         Self::new_unchecked(
             &qualified
@@ -339,25 +566,113 @@ impl ResourceIdentifier {
         )
     }
 
+    /// Return a new resource identifier with `segment` appended to this one using the path
+    /// separator character. The result is validated, so an error is returned if either
+    /// identifier was constructed with `new_unchecked` and is not valid.
+    ///
+    /// ```rust
+    /// use aws_arn::ResourceIdentifier;
+    /// use std::str::FromStr;
+    ///
+    /// let bucket = ResourceIdentifier::from_str("my-bucket").unwrap();
+    /// let key = ResourceIdentifier::from_str("photos/cat.png").unwrap();
+    /// assert_eq!(bucket.join_path(&key).unwrap().to_string(), "my-bucket/photos/cat.png");
+    /// ```
+    pub fn join_path(&self, segment: &ResourceIdentifier) -> ArnResult<Self> {
+        Self::try_from(format!("{}{}{}", self.0, PATH_SEPARATOR, segment.0))
+    }
+
+    /// Return a new resource identifier with `segment` appended to this one using the qualifier
+    /// separator character. As with `join_path`, the result is validated.
+    ///
+    /// ```rust
+    /// use aws_arn::ResourceIdentifier;
+    /// use std::str::FromStr;
+    ///
+    /// let function = ResourceIdentifier::from_str("function:my-function").unwrap();
+    /// let version = ResourceIdentifier::from_str("3").unwrap();
+    /// assert_eq!(
+    ///     function.join_qualifier(&version).unwrap().to_string(),
+    ///     "function:my-function:3"
+    /// );
+    /// ```
+    pub fn join_qualifier(&self, segment: &ResourceIdentifier) -> ArnResult<Self> {
+        Self::try_from(format!("{}{}{}", self.0, PART_SEPARATOR, segment.0))
+    }
+
     /// Return `true` if this identifier contains path separator characters, else `false`.
+    #[must_use]
     pub fn contains_path(&self) -> bool {
         self.0.contains(PATH_SEPARATOR)
     }
 
+    /// Return `true` if this identifier begins with the path separator character, as in the
+    /// API Gateway management resource `/restapis/a1b2c3`, else `false`.
+    #[must_use]
+    pub fn is_absolute_path(&self) -> bool {
+        self.0.starts_with(PATH_SEPARATOR)
+    }
+
     /// Return the list of path components when split using the path separator character.
+    /// A single leading path separator, see `is_absolute_path`, is skipped rather than
+    /// producing an empty first component.
+    #[must_use]
     pub fn path_split(&self) -> Vec<ResourceIdentifier> {
-        self.0
-            .split(PATH_SEPARATOR)
+        self.path_segments()
             .map(ResourceIdentifier::new_unchecked)
             .collect()
     }
 
+    /// Return an iterator over the path components when split using the path separator
+    /// character. Unlike `path_split` this borrows from the identifier and does not allocate.
+    ///
+    /// A single leading path separator is skipped, so `/restapis/a1b2c3` yields the segments
+    /// `restapis` and `a1b2c3`.
+    ///
+    /// ```rust
+    /// use aws_arn::ResourceIdentifier;
+    /// use std::str::FromStr;
+    ///
+    /// let resource = ResourceIdentifier::from_str("/restapis/a1b2c3").unwrap();
+    /// assert!(resource.is_absolute_path());
+    /// assert_eq!(resource.path_segments().collect::<Vec<_>>(), vec!["restapis", "a1b2c3"]);
+    /// ```
+    pub fn path_segments(&self) -> impl Iterator<Item = &str> {
+        self.0
+            .strip_prefix(PATH_SEPARATOR)
+            .unwrap_or(&self.0)
+            .split(PATH_SEPARATOR)
+    }
+
+    /// Return the final path component, the substring after the last path separator character.
+    /// If there is no path separator the whole identifier is returned, and if the identifier
+    /// ends with a path separator the result is empty.
+    #[must_use]
+    pub fn last_segment(&self) -> &str {
+        self.0
+            .rsplit_once(PATH_SEPARATOR)
+            .map_or(self.0.as_str(), |(_, last)| last)
+    }
+
     /// Return `true` if this identifier contains qualifier separator characters, else `false`.
+    #[must_use]
     pub fn contains_qualified(&self) -> bool {
         self.0.contains(PART_SEPARATOR)
     }
 
+    /// Return the shape of this identifier, based on which separator characters it contains.
+    #[must_use]
+    pub fn shape(&self) -> ResourceShape {
+        match (self.contains_path(), self.contains_qualified()) {
+            (false, false) => ResourceShape::Plain,
+            (true, false) => ResourceShape::Path,
+            (false, true) => ResourceShape::Qualified,
+            (true, true) => ResourceShape::Mixed,
+        }
+    }
+
     /// Return the list of path components when split using the qualifier separator character.
+    #[must_use]
     pub fn qualifier_split(&self) -> Vec<ResourceIdentifier> {
         self.0
             .split(PART_SEPARATOR)
@@ -365,23 +680,90 @@ impl ResourceIdentifier {
             .collect()
     }
 
+    /// Return an iterator over the qualifier components when split using the qualifier
+    /// separator character. Unlike `qualifier_split` this borrows from the identifier and does
+    /// not allocate.
+    pub fn qualifier_segments(&self) -> impl Iterator<Item = &str> {
+        self.0.split(PART_SEPARATOR)
+    }
+
     /// Return `true` if the identifier contains variables of the form
     /// `${name}`, else `false`.
+    #[must_use]
     pub fn has_variables(&self) -> bool {
         REGEX_VARIABLE.is_match(self.deref())
     }
 
     /// Replace any variables in the string with values from the context,
     /// returning a new value if the replacements result in a legal identifier
-    /// string. The
+    /// string. If the identifier contains no variables no new string is built for
+    /// the replacement, but the result is still validated.
     pub fn replace_variables<V>(&self, context: &HashMap<String, V>) -> ArnResult<Self>
     where
         V: Clone + Into<String>,
     {
+        self.expand_variables(context, |_, _| {})
+    }
+
+    /// Replace any variables in the string with values from the context, as
+    /// `replace_variables` does, also returning each variable found as a `(name, value)`
+    /// pair in the order they appear. Variables with no value in the context are left in
+    /// place and listed with a value of `None`.
+    ///
+    /// ```rust
+    /// use aws_arn::ResourceIdentifier;
+    /// use std::collections::HashMap;
+    /// use std::str::FromStr;
+    ///
+    /// let id = ResourceIdentifier::from_str("${partition}/${bucket}").unwrap();
+    /// let context: HashMap<String, &str> = vec![("partition".to_string(), "aws")].into_iter().collect();
+    /// let (expanded, substitutions) = id.replace_variables_logged(&context).unwrap();
+    /// assert_eq!(expanded.to_string(), "aws/${bucket}");
+    /// assert_eq!(
+    ///     substitutions,
+    ///     vec![
+    ///         ("partition".to_string(), Some("aws".to_string())),
+    ///         ("bucket".to_string(), None),
+    ///     ]
+    /// );
+    /// ```
+    pub fn replace_variables_logged<V>(
+        &self,
+        context: &HashMap<String, V>,
+    ) -> ArnResult<(Self, Vec<Substitution>)>
+    where
+        V: Clone + Into<String>,
+    {
+        let mut substitutions = Vec::new();
+        let expanded = self.expand_variables(context, |name, value| {
+            substitutions.push((name.to_string(), value.map(str::to_string)))
+        })?;
+        Ok((expanded, substitutions))
+    }
+
+    fn expand_variables<V, F>(
+        &self,
+        context: &HashMap<String, V>,
+        mut on_variable: F,
+    ) -> ArnResult<Self>
+    where
+        V: Clone + Into<String>,
+        F: FnMut(&str, Option<&str>),
+    {
+        if !self.has_variables() {
+            return if Self::is_valid(self) {
+                Ok(self.clone())
+            } else {
+                Err(invalid_resource(self))
+            };
+        }
         let new_text = REGEX_VARIABLE.replace_all(self.deref(), |caps: &Captures<'_>| {
             if let Some(value) = context.get(&caps[1]) {
-                value.clone().into()
+                let value: String = value.clone().into();
+                on_variable(&caps[1], Some(&value));
+                value
             } else {
+                on_variable(&caps[1], None);
                 format!("${{{}}}", &caps[1])
             }
         });
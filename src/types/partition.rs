@@ -1,9 +1,15 @@
 //! AWS partition information.
 
-use crate::ArnError;
+use crate::{ArnError, Region};
 
 /// A list of known partition identifiers from
 /// [docs.aws](https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html).
+///
+/// Parsing with `FromStr` is ASCII case-insensitive; `Display` always produces the canonical
+/// lowercase form.
+///
+/// This enum is non-exhaustive as new partitions are added over time; matches outside this crate
+/// must include a wildcard arm.
 #[derive(
     Debug,
     Default,
@@ -17,30 +23,83 @@ use crate::ArnError;
     strum::Display,
     strum::EnumString,
     strum::IntoStaticStr,
+    strum::VariantArray,
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_with::DeserializeFromStr, serde_with::SerializeDisplay)
 )]
-#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[strum(
     parse_err_fn = convert_partition_parse_err,
     parse_err_ty = ArnError,
+    ascii_case_insensitive,
 )]
+#[non_exhaustive]
 pub enum Partition {
     /// Corresponds to the partition "aws": AWS region
-    #[serde(rename = "aws")]
     #[strum(serialize = "aws")]
     #[default]
     Aws,
 
     /// Corresponds to the partition "aws-cn": AWS China regions
-    #[serde(rename = "aws-cn")]
     #[strum(serialize = "aws-cn")]
     AwsChina,
 
     /// Corresponds to the partition "aws-us-gov": AWS GovCloud (US) regions
-    #[serde(rename = "aws-us-gov")]
     #[strum(serialize = "aws-us-gov")]
     AwsUsGov,
 }
 
+const AWS_REGIONS: &[Region] = &[
+    Region::AfSouth1,
+    Region::ApEast1,
+    Region::ApNortheast1,
+    Region::ApNortheast2,
+    Region::ApNortheast3,
+    Region::ApSoutheast1,
+    Region::ApSoutheast2,
+    Region::ApSouth1,
+    Region::CaCentral1,
+    Region::EuCentral1,
+    Region::EuNorth1,
+    Region::EuSouth1,
+    Region::EuWest1,
+    Region::EuWest2,
+    Region::EuWest3,
+    Region::MeSouth1,
+    Region::SaEast1,
+    Region::UsEast1,
+    Region::UsEast2,
+    Region::UsWest1,
+    Region::UsWest2,
+    Region::AwsGlobal,
+];
+
+const AWS_CHINA_REGIONS: &[Region] = &[Region::CnNorth1, Region::CnNorthwest1];
+
+const AWS_US_GOV_REGIONS: &[Region] =
+    &[Region::UsGovWest1, Region::UsGovEast1, Region::UsGovGlobal];
+
+impl Partition {
+    /// Return the known regions within this partition.
+    pub fn regions(&self) -> &'static [Region] {
+        match self {
+            Self::Aws => AWS_REGIONS,
+            Self::AwsChina => AWS_CHINA_REGIONS,
+            Self::AwsUsGov => AWS_US_GOV_REGIONS,
+        }
+    }
+
+    /// Return the partition that contains `region`.
+    pub fn from_region(region: &Region) -> Self {
+        match region {
+            Region::CnNorth1 | Region::CnNorthwest1 => Self::AwsChina,
+            Region::UsGovWest1 | Region::UsGovEast1 | Region::UsGovGlobal => Self::AwsUsGov,
+            _ => Self::Aws,
+        }
+    }
+}
+
 fn convert_partition_parse_err(p: &str) -> ArnError {
     ArnError::InvalidPartition(p.to_string())
 }
@@ -4,6 +4,12 @@ use crate::ArnError;
 
 /// A list of known region identifiers from
 /// [docs.aws](https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/using-regions-availability-zones.html).
+///
+/// Parsing with `FromStr` is ASCII case-insensitive; `Display` always produces the canonical
+/// lowercase form.
+///
+/// This enum is non-exhaustive as new regions are added over time; matches outside this crate
+/// must include a wildcard arm.
 #[derive(
     Debug,
     Clone,
@@ -16,152 +22,236 @@ use crate::ArnError;
     strum::Display,
     strum::IntoStaticStr,
     strum::EnumString,
+    strum::VariantArray,
 )]
 #[strum(
     serialize_all = "kebab-case",
     parse_err_fn = convert_region_parse_err,
     parse_err_ty = ArnError,
-    use_phf,
+    ascii_case_insensitive,
 )]
-#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_with::DeserializeFromStr, serde_with::SerializeDisplay)
+)]
+#[non_exhaustive]
 pub enum Region {
     /// Corresponds to the region "af-south-1": Africa (Cape Town)
     #[strum(serialize = "af-south-1")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "af-south-1")]
     AfSouth1,
 
     /// Corresponds to the region "ap-east-1": Asia Pacific (Hong Kong)
     #[strum(serialize = "ap-east-1")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "ap-east-1")]
     ApEast1,
 
     /// Corresponds to the region "ap-northeast-1": Asia Pacific (Tokyo)
     #[strum(serialize = "ap-northeast-1")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "ap-northeast-1")]
     ApNortheast1,
 
     /// Corresponds to the region "ap-northeast-2": Asia Pacific (Seoul)
     #[strum(serialize = "ap-northeast-2")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "ap-northeast-2")]
     ApNortheast2,
 
     /// Corresponds to the region "ap-northeast-3": Asia Pacific (Osaka)
     #[strum(serialize = "ap-northeast-3")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "ap-northeast-3")]
     ApNortheast3,
 
     /// Corresponds to the region "ap-southeast-1": Asia Pacific (Singapore)
     #[strum(serialize = "ap-southeast-1")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "ap-southeast-1")]
     ApSoutheast1,
 
     /// Corresponds to the region "ap-southeast-2": Asia Pacific (Sydney)
     #[strum(serialize = "ap-southeast-2")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "ap-southeast-2")]
     ApSoutheast2,
 
     /// Corresponds to the region "ap-south-1": Asia Pacific (Mumbai)
     #[strum(serialize = "ap-south-1")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "ap-south-1")]
     ApSouth1,
 
+    /// Corresponds to the pseudo-region "aws-global", used by some global services in the
+    /// `aws` partition in place of a region.
+    #[strum(serialize = "aws-global")]
+    AwsGlobal,
+
     /// Corresponds to the region "ca-central-1": Canada (Central)
     #[strum(serialize = "ca-central-1")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "ca-central-1")]
     CaCentral1,
 
+    /// Corresponds to the region "cn-north-1": China (Beijing)
+    #[strum(serialize = "cn-north-1")]
+    CnNorth1,
+
+    /// Corresponds to the region "cn-northwest-1": China (Ningxia)
+    #[strum(serialize = "cn-northwest-1")]
+    CnNorthwest1,
+
     /// Corresponds to the region "eu-central-1": Europe (Frankfurt)
     #[strum(serialize = "eu-central-1")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "eu-central-1")]
     EuCentral1,
 
     /// Corresponds to the region "eu-north-1": Europe (Stockholm)
     #[strum(serialize = "eu-north-1")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "eu-north-1")]
     EuNorth1,
 
     /// Corresponds to the region "eu-south-1": Europe (Milan)
     #[strum(serialize = "eu-south-1")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "eu-south-1")]
     EuSouth1,
 
     /// Corresponds to the region "eu-west-1": Europe (Ireland)
     #[strum(serialize = "eu-west-1")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "eu-west-1")]
     EuWest1,
 
     /// Corresponds to the region "eu-west-2": Europe (London)
     #[strum(serialize = "eu-west-2")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "eu-west-2")]
     EuWest2,
 
     /// Corresponds to the region "eu-west-3": Europe (Paris)
     #[strum(serialize = "eu-west-3")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "eu-west-3")]
     EuWest3,
 
-    /// Corresponds to the region "me-south-1": Europe (Bahrain)
+    /// Corresponds to the region "me-south-1": Middle East (Bahrain)
     #[strum(serialize = "me-south-1")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "me-south-1")]
     MeSouth1,
 
     /// Corresponds to the region "sa-east-1": South America (São Paulo)
     #[strum(serialize = "sa-east-1")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "sa-east-1")]
     SaEast1,
 
     /// Corresponds to the region "us-east-1": US East (N. Virginia)
     #[strum(serialize = "us-east-1")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "us-east-1")]
     UsEast1,
 
     /// Corresponds to the region "us-east-2": US East (Ohio)
     #[strum(serialize = "us-east-2")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "us-east-2")]
     UsEast2,
 
     /// Corresponds to the region "us-west-1": US West (N. California)
     #[strum(serialize = "us-west-1")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "us-west-1")]
     UsWest1,
 
     /// Corresponds to the region "us-west-2": US West (Oregon)
     #[strum(serialize = "us-west-2")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "us-west-2")]
     UsWest2,
 
     /// Corresponds to the region "us-gov-west-1": US Gov West
     #[strum(serialize = "us-gov-west-1")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "us-gov-west-1")]
     UsGovWest1,
 
     /// Corresponds to the region "us-gov-east-1": US Gov East
     #[strum(serialize = "us-gov-east-1")]
-    #[cfg(feature = "serde")]
-    #[serde(rename = "us-gov-east-1")]
     UsGovEast1,
+
+    /// Corresponds to the pseudo-region "us-gov-global", used by some global services in the
+    /// `aws-us-gov` partition in place of a region.
+    #[strum(serialize = "us-gov-global")]
+    UsGovGlobal,
+}
+
+impl Region {
+    /// Return the human-readable name of this region, as used in the AWS console, for
+    /// example "US East (N. Virginia)" for `us-east-1`.
+    pub fn long_name(&self) -> &'static str {
+        match self {
+            Self::AfSouth1 => "Africa (Cape Town)",
+            Self::ApEast1 => "Asia Pacific (Hong Kong)",
+            Self::ApNortheast1 => "Asia Pacific (Tokyo)",
+            Self::ApNortheast2 => "Asia Pacific (Seoul)",
+            Self::ApNortheast3 => "Asia Pacific (Osaka)",
+            Self::ApSoutheast1 => "Asia Pacific (Singapore)",
+            Self::ApSoutheast2 => "Asia Pacific (Sydney)",
+            Self::ApSouth1 => "Asia Pacific (Mumbai)",
+            Self::AwsGlobal => "AWS Global",
+            Self::CaCentral1 => "Canada (Central)",
+            Self::CnNorth1 => "China (Beijing)",
+            Self::CnNorthwest1 => "China (Ningxia)",
+            Self::EuCentral1 => "Europe (Frankfurt)",
+            Self::EuNorth1 => "Europe (Stockholm)",
+            Self::EuSouth1 => "Europe (Milan)",
+            Self::EuWest1 => "Europe (Ireland)",
+            Self::EuWest2 => "Europe (London)",
+            Self::EuWest3 => "Europe (Paris)",
+            Self::MeSouth1 => "Middle East (Bahrain)",
+            Self::SaEast1 => "South America (São Paulo)",
+            Self::UsEast1 => "US East (N. Virginia)",
+            Self::UsEast2 => "US East (Ohio)",
+            Self::UsWest1 => "US West (N. California)",
+            Self::UsWest2 => "US West (Oregon)",
+            Self::UsGovGlobal => "AWS GovCloud (US) Global",
+            Self::UsGovWest1 => "AWS GovCloud (US-West)",
+            Self::UsGovEast1 => "AWS GovCloud (US-East)",
+        }
+    }
+
+    /// Return `true` if this is a pseudo-region, such as `aws-global`, that denotes a global
+    /// service endpoint rather than a physical location, else `false`.
+    pub fn is_global(&self) -> bool {
+        matches!(self, Self::AwsGlobal | Self::UsGovGlobal)
+    }
+
+    /// Return `true` if this region must be explicitly enabled on an account before it can be
+    /// used, see
+    /// [docs.aws](https://docs.aws.amazon.com/general/latest/gr/rande-manage.html), else
+    /// `false`.
+    pub fn is_opt_in(&self) -> bool {
+        matches!(
+            self,
+            Self::AfSouth1 | Self::ApEast1 | Self::EuSouth1 | Self::MeSouth1
+        )
+    }
+
+    /// Return the continent, or geographic area, in which this region is located.
+    pub fn continent(&self) -> Continent {
+        match self {
+            Self::AfSouth1 => Continent::Africa,
+            Self::AwsGlobal | Self::UsGovGlobal => Continent::Global,
+            Self::ApEast1
+            | Self::ApNortheast1
+            | Self::ApNortheast2
+            | Self::ApNortheast3
+            | Self::ApSoutheast1
+            | Self::ApSouth1
+            | Self::CnNorth1
+            | Self::CnNorthwest1 => Continent::Asia,
+            Self::EuCentral1
+            | Self::EuNorth1
+            | Self::EuSouth1
+            | Self::EuWest1
+            | Self::EuWest2
+            | Self::EuWest3 => Continent::Europe,
+            Self::MeSouth1 => Continent::MiddleEast,
+            Self::CaCentral1
+            | Self::UsEast1
+            | Self::UsEast2
+            | Self::UsWest1
+            | Self::UsWest2
+            | Self::UsGovWest1
+            | Self::UsGovEast1 => Continent::NorthAmerica,
+            Self::ApSoutheast2 => Continent::Oceania,
+            Self::SaEast1 => Continent::SouthAmerica,
+        }
+    }
+}
+
+/// The geographic areas in which regions are located, as returned by [`Region::continent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Continent {
+    /// Africa
+    Africa,
+    /// Asia
+    Asia,
+    /// Europe
+    Europe,
+    /// The Middle East
+    MiddleEast,
+    /// North America
+    NorthAmerica,
+    /// Oceania, including Australia
+    Oceania,
+    /// South America
+    SouthAmerica,
+    /// Not a physical location, used by the global pseudo-regions such as `aws-global`
+    Global,
 }
 
 fn convert_region_parse_err(r: &str) -> ArnError {
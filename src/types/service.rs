@@ -2,8 +2,40 @@
 #![allow(missing_docs)]
 
 use crate::ArnError;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use strum::VariantArray;
+
+static SERVICES_BY_ID: LazyLock<HashMap<u16, Service>> = LazyLock::new(|| {
+    Service::VARIANTS
+        .iter()
+        .map(|service| (service.id(), service.clone()))
+        .collect()
+});
+
+// Where several services share an endpoint prefix, the one whose ARN namespace is the same
+// prefix wins, otherwise the first in declaration order.
+static SERVICES_BY_ENDPOINT_PREFIX: LazyLock<HashMap<&'static str, Service>> =
+    LazyLock::new(|| {
+        let mut services = HashMap::new();
+        for service in Service::VARIANTS {
+            let prefix = service.endpoint_prefix();
+            if prefix == service.as_str() {
+                let _ = services.insert(prefix, service.clone());
+            } else {
+                let _ = services.entry(prefix).or_insert_with(|| service.clone());
+            }
+        }
+        services
+    });
 
 /// A list of known service identifiers.
+///
+/// Parsing with `FromStr` is ASCII case-insensitive, so `"S3"` and `"s3"` are both
+/// accepted; `Display` always produces the canonical lowercase namespace.
+///
+/// This enum is non-exhaustive as new services are added over time; matches outside this crate
+/// must include a wildcard arm.
 #[derive(
     Debug,
     Clone,
@@ -16,16 +48,18 @@ use crate::ArnError;
     strum::IntoStaticStr,
     strum::Display,
     strum::EnumString,
+    strum::VariantArray,
 )]
 #[strum(
     parse_err_fn = convert_service_parse_err,
     parse_err_ty = ArnError,
-    use_phf
+    ascii_case_insensitive
 )]
 #[cfg_attr(
     feature = "serde",
     derive(serde_with::DeserializeFromStr, serde_with::SerializeDisplay)
 )]
+#[non_exhaustive]
 pub enum Service {
     #[strum(serialize = "accessanalyzer")]
     AccessAnalyzer,
@@ -126,6 +160,9 @@ pub enum Service {
     #[strum(serialize = "cloudformation")]
     CloudFormation,
 
+    #[strum(serialize = "cloudfront")]
+    CloudFront,
+
     #[strum(serialize = "cloudhsm")]
     CloudHsm,
 
@@ -465,6 +502,11 @@ pub enum Service {
     #[strum(serialize = "lambda")]
     Lambda,
 
+    /// The ARN namespace shared by Amazon Lex V1 and V2 resources; `lex-models` and
+    /// `lexv2-models` are API names and do not appear in resource ARNs.
+    #[strum(serialize = "lex")]
+    Lex,
+
     #[strum(serialize = "lex-models")]
     LexModels,
 
@@ -823,6 +865,761 @@ pub enum Service {
     XRay,
 }
 
+impl Service {
+    /// Return the ARN namespace of this service, as used in the service component of an ARN,
+    /// without allocating. This is the same value produced by `Display`.
+    pub fn as_str(&self) -> &'static str {
+        self.into()
+    }
+
+    /// Return the prefix used in the hostname of this service's API endpoints, for example
+    /// `monitoring` in `monitoring.us-east-1.amazonaws.com`. For most services this is the same
+    /// as the ARN namespace, but a number of services diverge.
+    pub fn endpoint_prefix(&self) -> &'static str {
+        match self {
+            Self::ApiGatewayManagementApi => "execute-api",
+            Self::ApiGatewayV2 => "apigateway",
+            Self::ApplicationInsights => "applicationinsights",
+            Self::AugmentedAiRuntime => "a2i-runtime.sagemaker",
+            Self::CloudWatch => "monitoring",
+            Self::ConnectContactLens => "contact-lens",
+            Self::ConnectParticipant => "participant.connect",
+            Self::DynamoDbStreams => "streams.dynamodb",
+            Self::Ec2containerRegistryPublic => "api.ecr-public",
+            Self::Efs => "elasticfilesystem",
+            Self::ElasticInference => "api.elastic-inference",
+            Self::ElasticLoadBalancingV2 => "elasticloadbalancing",
+            Self::ElasticMapReduce => "elasticmapreduce",
+            Self::Elb => "elasticloadbalancing",
+            Self::IoT1clickDevices => "devices.iot1click",
+            Self::IoT1clickProjects => "projects.iot1click",
+            Self::IoTData => "data-ats.iot",
+            Self::IoTDeviceAdvisor => "api.iotdeviceadvisor",
+            Self::IoTEventsData => "data.iotevents",
+            Self::IoTFleetHub => "api.fleethub.iot",
+            Self::IoTJobsData => "data.jobs.iot",
+            Self::IoTSecureTunneling => "api.tunneling.iot",
+            Self::IoTWireless => "api.iotwireless",
+            Self::LexModels => "models.lex",
+            Self::LexRuntime => "runtime.lex",
+            Self::LexV2Models => "models-v2-lex",
+            Self::LexV2Runtime => "runtime-v2-lex",
+            Self::MarketplaceCatalog => "catalog.marketplace",
+            Self::MarketplaceEntitlement => "entitlement.marketplace",
+            Self::MarketplaceMetering => "metering.marketplace",
+            Self::MechanicalTurk => "mturk-requester",
+            Self::MediaStoreData => "data.mediastore",
+            Self::PinpointEmail => "email",
+            Self::PinpointSmsVoice => "sms-voice.pinpoint",
+            Self::S3Control => "s3-control",
+            Self::S3Outposts => "s3-outposts",
+            Self::SageMakerFeatureStoreRuntime => "featurestore-runtime.sagemaker",
+            Self::SageMakerRuntime => "runtime.sagemaker",
+            Self::SagemakerEdgeManager => "edge.sagemaker",
+            Self::SimpleEmail => "email",
+            Self::SimpleEmailV2 => "email",
+            Self::SingleSignOn => "portal.sso",
+            Self::SingleSignOnAdmin => "sso",
+            Self::SingleSignOnOpenIdConnect => "oidc",
+            Self::StepFunctions => "states",
+            Self::TimestreamQuery => "query.timestream",
+            Self::TimestreamWrite => "ingest.timestream",
+            _ => self.as_str(),
+        }
+    }
+
+    /// Return the service whose API endpoints use the hostname prefix `prefix`, the inverse of
+    /// `endpoint_prefix`, or `None` if no known service uses it. Where several services share
+    /// a prefix, the one whose ARN namespace is also `prefix` is returned.
+    ///
+    /// ```rust
+    /// use aws_arn::Service;
+    ///
+    /// assert_eq!(Service::from_endpoint_prefix("monitoring"), Some(Service::CloudWatch));
+    /// assert_eq!(Service::from_endpoint_prefix("s3"), Some(Service::S3));
+    /// assert_eq!(Service::from_endpoint_prefix("nope"), None);
+    /// ```
+    pub fn from_endpoint_prefix(prefix: &str) -> Option<Self> {
+        SERVICES_BY_ENDPOINT_PREFIX.get(prefix).cloned()
+    }
+
+    /// Return `true` if resources in this service are global, and so their ARNs must
+    /// not include a region, else `false`.
+    pub fn is_global(&self) -> bool {
+        matches!(
+            self,
+            Self::Artifact
+                | Self::Budgets
+                | Self::CloudFront
+                | Self::CostExplorer
+                | Self::GlobalAccelerator
+                | Self::IdentityAccessManagement
+                | Self::Organizations
+                | Self::Route53
+                | Self::SavingsPlans
+                | Self::SecurityToken
+                | Self::Shield
+                | Self::WebApplicationFirewall
+        )
+    }
+
+    /// Return `true` if resources in this service are always located in a region, and
+    /// so their ARNs must include a region, else `false`.
+    ///
+    /// Note that a service may be neither global nor regional; for example S3 bucket
+    /// ARNs omit the region while S3 batch job ARNs include one.
+    pub fn is_regional(&self) -> bool {
+        matches!(
+            self,
+            Self::CloudFormation
+                | Self::CloudTrail
+                | Self::CloudWatch
+                | Self::CloudWatchLogs
+                | Self::DynamoDb
+                | Self::Ec2
+                | Self::Ec2ContainerRegistry
+                | Self::Ec2ContainerService
+                | Self::KeyManagement
+                | Self::Kinesis
+                | Self::Lambda
+                | Self::RelationalDatabaseService
+                | Self::SecretsManager
+                | Self::SimpleNotification
+                | Self::SimpleQueue
+        )
+    }
+
+    /// Return `true` if ARNs for this service must have a non-empty resource component, else
+    /// `false`. This is conservatively `true` for every known service; it is checked by
+    /// [`ResourceName::validate`](crate::ResourceName::validate) so that any exceptions can be
+    /// added here.
+    pub fn requires_resource(&self) -> bool {
+        true
+    }
+
+    /// Return a stable numeric identifier for this service, suitable for compact storage.
+    ///
+    /// Identifiers are append-only: each service keeps its identifier across releases, new
+    /// services are given the next unused number, and the identifier of a removed service is
+    /// never reused. Use [`Service::from_id`] to convert back.
+    pub fn id(&self) -> u16 {
+        match self {
+            Self::AccessAnalyzer => 1,
+            Self::CertificateManager => 2,
+            Self::CertificateManagerPrivateCa => 3,
+            Self::AlexaForBusiness => 4,
+            Self::Prometheus => 5,
+            Self::Amplify => 6,
+            Self::AmplifyBackend => 7,
+            Self::ApiGateway => 8,
+            Self::ApiGatewayExecuteApi => 9,
+            Self::ApiGatewayManagementApi => 10,
+            Self::ApiGatewayV2 => 11,
+            Self::AppConfig => 12,
+            Self::AppFlow => 13,
+            Self::AppIntegrations => 14,
+            Self::ApplicationAutoscaling => 15,
+            Self::ApplicationInsights => 16,
+            Self::AppMesh => 17,
+            Self::AppStream => 18,
+            Self::AppSync => 19,
+            Self::Artifact => 20,
+            Self::Athena => 21,
+            Self::AuditManager => 22,
+            Self::AutoScaling => 23,
+            Self::AutoScalingPlans => 24,
+            Self::Backup => 25,
+            Self::Batch => 26,
+            Self::Braket => 27,
+            Self::Budgets => 28,
+            Self::CostExplorer => 29,
+            Self::Chime => 30,
+            Self::Cloud9 => 31,
+            Self::CloudDirectory => 32,
+            Self::CloudFormation => 33,
+            Self::CloudFront => 34,
+            Self::CloudHsm => 35,
+            Self::CloudHsmV2 => 36,
+            Self::CloudSearch => 37,
+            Self::CloudSearchDomain => 38,
+            Self::CloudTrail => 39,
+            Self::CloudWatch => 40,
+            Self::CodeArtifact => 41,
+            Self::CodeBuild => 42,
+            Self::CodeCommit => 43,
+            Self::CodeDeploy => 44,
+            Self::CodeGuruReviewer => 45,
+            Self::CodeGuruProfiler => 46,
+            Self::CodePipeline => 47,
+            Self::CodeStar => 48,
+            Self::CodeStarConnections => 49,
+            Self::CodeStarNotifications => 50,
+            Self::CognitoIdentity => 51,
+            Self::CognitoIdentityProvider => 52,
+            Self::CognitoSync => 53,
+            Self::Comprehend => 54,
+            Self::ComprehendMedical => 55,
+            Self::ComputeOptimizer => 56,
+            Self::Config => 57,
+            Self::Connect => 58,
+            Self::ConnectContactLens => 59,
+            Self::ConnectParticipant => 60,
+            Self::CostUsageReport => 61,
+            Self::CustomerProfiles => 62,
+            Self::GlueDataBrew => 63,
+            Self::DataExchange => 64,
+            Self::DataPipeline => 65,
+            Self::DataSync => 66,
+            Self::DynamoDbAccelerator => 67,
+            Self::Detective => 68,
+            Self::DeviceFarm => 69,
+            Self::DevOpsGuru => 70,
+            Self::DirectConnect => 71,
+            Self::Discovery => 72,
+            Self::DataLifecycleManager => 73,
+            Self::DatabaseMigration => 74,
+            Self::DocumentDb => 75,
+            Self::DynamoDb => 76,
+            Self::DynamoDbStreams => 77,
+            Self::ElasticBlockStore => 78,
+            Self::Ec2 => 79,
+            Self::Ec2InstanceConnect => 80,
+            Self::Ec2ContainerRegistry => 81,
+            Self::Ec2containerRegistryPublic => 82,
+            Self::Ec2ContainerService => 83,
+            Self::Efs => 84,
+            Self::ElasticFileSystem => 85,
+            Self::ElasticKubernetes => 86,
+            Self::ElasticInference => 87,
+            Self::Elasticache => 88,
+            Self::ElasticBeanstalk => 89,
+            Self::ElasticTranscoder => 90,
+            Self::Elb => 91,
+            Self::ElasticLoadBalancing => 92,
+            Self::ElasticLoadBalancingV2 => 93,
+            Self::ElasticMapReduce => 94,
+            Self::ElasticMapReduceContainers => 95,
+            Self::ElasticsearchService => 96,
+            Self::EventBridge => 97,
+            Self::Firehose => 98,
+            Self::FaultInjectionSimulator => 99,
+            Self::FirewallManagementService => 100,
+            Self::ForecastService => 101,
+            Self::ForecastQueryService => 102,
+            Self::FraudDetector => 103,
+            Self::Fsx => 104,
+            Self::GameLift => 105,
+            Self::Glacier => 106,
+            Self::GlobalAccelerator => 107,
+            Self::Glue => 108,
+            Self::Greengrass => 109,
+            Self::GreengrassV2 => 110,
+            Self::GroundStation => 111,
+            Self::GuardDuty => 112,
+            Self::Health => 113,
+            Self::HealthLake => 114,
+            Self::Honeycode => 115,
+            Self::IdentityAccessManagement => 116,
+            Self::IdentityStore => 117,
+            Self::ImageBuilder => 118,
+            Self::ImportExport => 119,
+            Self::Inspector => 120,
+            Self::IoT => 121,
+            Self::IoTData => 122,
+            Self::IoTJobsData => 123,
+            Self::IoT1clickDevices => 124,
+            Self::IoT1clickProjects => 125,
+            Self::IoTAnalytics => 126,
+            Self::IoTDeviceAdvisor => 127,
+            Self::IoTEvents => 128,
+            Self::IoTEventsData => 129,
+            Self::IoTFleetHub => 130,
+            Self::IoTSecureTunneling => 131,
+            Self::IoTSitewise => 132,
+            Self::IoTThingsGraph => 133,
+            Self::IoTWireless => 134,
+            Self::InteractiveVideo => 135,
+            Self::Kafka => 136,
+            Self::Kendra => 137,
+            Self::Kinesis => 138,
+            Self::KinesisVideoArchivedMedia => 139,
+            Self::KinesisVideoMedia => 140,
+            Self::KinesisVideoSignaling => 141,
+            Self::KinesisAnalytics => 142,
+            Self::KinesisAnalyticsV2 => 143,
+            Self::KinesisVideo => 144,
+            Self::KeyManagement => 145,
+            Self::LakeFormation => 146,
+            Self::Lambda => 147,
+            Self::Lex => 148,
+            Self::LexModels => 149,
+            Self::LexRuntime => 150,
+            Self::LexV2Models => 151,
+            Self::LexV2Runtime => 152,
+            Self::LicenseManager => 153,
+            Self::Lightsail => 154,
+            Self::Location => 155,
+            Self::CloudWatchLogs => 156,
+            Self::LookoutEquipment => 157,
+            Self::LookoutMetrics => 158,
+            Self::LookoutVision => 159,
+            Self::MachineLearning => 160,
+            Self::Macie => 161,
+            Self::Macie2 => 162,
+            Self::ManagedBlockchain => 163,
+            Self::MarketplaceCatalog => 164,
+            Self::MarketplaceEntitlement => 165,
+            Self::MarketplaceCommerceAnalytics => 166,
+            Self::MediaConnect => 167,
+            Self::MediaConvert => 168,
+            Self::MediaLive => 169,
+            Self::MediaPackage => 170,
+            Self::MediaPackageVod => 171,
+            Self::MediaStore => 172,
+            Self::MediaStoreData => 173,
+            Self::MediaTailor => 174,
+            Self::MarketplaceMetering => 175,
+            Self::MigrationHub => 176,
+            Self::ApplicationMigration => 177,
+            Self::MigrationHubConfig => 178,
+            Self::Mobile => 179,
+            Self::Mq => 180,
+            Self::MechanicalTurk => 181,
+            Self::ManagedWorkflowsForApacheAirflow => 182,
+            Self::Neptune => 183,
+            Self::NetworkFirewall => 184,
+            Self::NetworkManager => 185,
+            Self::OpsWorks => 186,
+            Self::OpsWorksCm => 187,
+            Self::Organizations => 188,
+            Self::Outposts => 189,
+            Self::Personalize => 190,
+            Self::PersonalizeEvents => 191,
+            Self::PersonalizeRuntime => 192,
+            Self::PerformanceInsights => 193,
+            Self::Pinpoint => 194,
+            Self::PinpointEmail => 195,
+            Self::PinpointSmsVoice => 196,
+            Self::Polly => 197,
+            Self::Pricing => 198,
+            Self::Qldb => 199,
+            Self::QldbSession => 200,
+            Self::QuickSight => 201,
+            Self::ResourceAccessManager => 202,
+            Self::RelationalDatabaseService => 203,
+            Self::RdsDataService => 204,
+            Self::Redshift => 205,
+            Self::RedshiftDataApiService => 206,
+            Self::Rekognition => 207,
+            Self::ResourceGroups => 208,
+            Self::ResourceGroupsTaggingApi => 209,
+            Self::RoboMaker => 210,
+            Self::Route53 => 211,
+            Self::Route53Domains => 212,
+            Self::Route53Resolver => 213,
+            Self::S3 => 214,
+            Self::S3Control => 215,
+            Self::S3Outposts => 216,
+            Self::SageMaker => 217,
+            Self::AugmentedAiRuntime => 218,
+            Self::SagemakerEdgeManager => 219,
+            Self::SageMakerFeatureStoreRuntime => 220,
+            Self::SageMakerRuntime => 221,
+            Self::SavingsPlans => 222,
+            Self::EventBridgeSchemaRegistry => 223,
+            Self::SimpleDb => 224,
+            Self::SecretsManager => 225,
+            Self::SecurityHub => 226,
+            Self::ServerlessApplicationRepository => 227,
+            Self::ServiceQuotas => 228,
+            Self::ServiceCatalog => 229,
+            Self::ServiceCatalogAppRegistry => 230,
+            Self::ServiceDiscovery => 231,
+            Self::SimpleEmail => 232,
+            Self::SimpleEmailV2 => 233,
+            Self::Shield => 234,
+            Self::Signer => 235,
+            Self::ServerMigration => 236,
+            Self::Snowball => 237,
+            Self::SimpleNotification => 238,
+            Self::SimpleQueue => 239,
+            Self::SimpleSystemsManager => 240,
+            Self::SingleSignOn => 241,
+            Self::SingleSignOnAdmin => 242,
+            Self::SingleSignOnOpenIdConnect => 243,
+            Self::StorageGateway => 244,
+            Self::SecurityToken => 245,
+            Self::States => 246,
+            Self::StepFunctions => 247,
+            Self::Support => 248,
+            Self::SimpleWorkflow => 249,
+            Self::CloudWatchSynthetics => 250,
+            Self::Textract => 251,
+            Self::TimestreamQuery => 252,
+            Self::TimestreamWrite => 253,
+            Self::Transcribe => 254,
+            Self::Transfer => 255,
+            Self::Translate => 256,
+            Self::TrustedAdvisor => 257,
+            Self::WebApplicationFirewall => 258,
+            Self::WebApplicationFirewallRegional => 259,
+            Self::WebApplicationFirewallV2 => 260,
+            Self::WellArchitected => 261,
+            Self::WorkDocs => 262,
+            Self::WorkLink => 263,
+            Self::WorkMail => 264,
+            Self::WorkMailMessageFlow => 265,
+            Self::WorkSpaces => 266,
+            Self::XRay => 267,
+        }
+    }
+
+    /// Return the service with the stable numeric identifier `id`, as returned by
+    /// [`Service::id`], or `None` if no service has that identifier.
+    ///
+    /// ```rust
+    /// use aws_arn::Service;
+    ///
+    /// assert_eq!(Service::from_id(Service::S3.id()), Some(Service::S3));
+    /// assert_eq!(Service::from_id(0), None);
+    /// ```
+    pub fn from_id(id: u16) -> Option<Self> {
+        SERVICES_BY_ID.get(&id).cloned()
+    }
+
+    /// Return the top-level category this service is grouped under in the AWS console and
+    /// documentation.
+    pub fn category(&self) -> ServiceCategory {
+        match self {
+            Self::Athena
+            | Self::CloudSearch
+            | Self::CloudSearchDomain
+            | Self::DataExchange
+            | Self::DataPipeline
+            | Self::ElasticMapReduce
+            | Self::ElasticMapReduceContainers
+            | Self::ElasticsearchService
+            | Self::Firehose
+            | Self::Glue
+            | Self::GlueDataBrew
+            | Self::Kafka
+            | Self::Kinesis
+            | Self::KinesisAnalytics
+            | Self::KinesisAnalyticsV2
+            | Self::LakeFormation
+            | Self::QuickSight
+            | Self::Redshift
+            | Self::RedshiftDataApiService => ServiceCategory::Analytics,
+            Self::AppFlow
+            | Self::EventBridge
+            | Self::EventBridgeSchemaRegistry
+            | Self::ManagedWorkflowsForApacheAirflow
+            | Self::MechanicalTurk
+            | Self::Mq
+            | Self::SimpleNotification
+            | Self::SimpleQueue
+            | Self::SimpleWorkflow
+            | Self::States
+            | Self::StepFunctions => ServiceCategory::ApplicationIntegration,
+            Self::ManagedBlockchain => ServiceCategory::Blockchain,
+            Self::AlexaForBusiness
+            | Self::Chime
+            | Self::Honeycode
+            | Self::WorkDocs
+            | Self::WorkMail
+            | Self::WorkMailMessageFlow => ServiceCategory::BusinessApplications,
+            Self::Budgets
+            | Self::CostExplorer
+            | Self::CostUsageReport
+            | Self::Pricing
+            | Self::SavingsPlans => ServiceCategory::CloudFinancialManagement,
+            Self::AutoScaling
+            | Self::Batch
+            | Self::Ec2
+            | Self::Ec2InstanceConnect
+            | Self::ElasticBeanstalk
+            | Self::ImageBuilder
+            | Self::Lambda
+            | Self::Lightsail
+            | Self::Outposts
+            | Self::ServerlessApplicationRepository => ServiceCategory::Compute,
+            Self::Ec2ContainerRegistry
+            | Self::Ec2containerRegistryPublic
+            | Self::Ec2ContainerService
+            | Self::ElasticKubernetes => ServiceCategory::Containers,
+            Self::Support => ServiceCategory::CustomerEnablement,
+            Self::AppIntegrations
+            | Self::Connect
+            | Self::ConnectContactLens
+            | Self::ConnectParticipant
+            | Self::CustomerProfiles
+            | Self::Pinpoint
+            | Self::PinpointEmail
+            | Self::PinpointSmsVoice
+            | Self::SimpleEmail
+            | Self::SimpleEmailV2 => ServiceCategory::CustomerEngagement,
+            Self::DocumentDb
+            | Self::DynamoDb
+            | Self::DynamoDbAccelerator
+            | Self::DynamoDbStreams
+            | Self::Elasticache
+            | Self::Neptune
+            | Self::PerformanceInsights
+            | Self::Qldb
+            | Self::QldbSession
+            | Self::RdsDataService
+            | Self::RelationalDatabaseService
+            | Self::SimpleDb
+            | Self::TimestreamQuery
+            | Self::TimestreamWrite => ServiceCategory::Database,
+            Self::Cloud9
+            | Self::CodeArtifact
+            | Self::CodeBuild
+            | Self::CodeCommit
+            | Self::CodeDeploy
+            | Self::CodeGuruProfiler
+            | Self::CodeGuruReviewer
+            | Self::CodePipeline
+            | Self::CodeStar
+            | Self::CodeStarConnections
+            | Self::CodeStarNotifications
+            | Self::FaultInjectionSimulator
+            | Self::XRay => ServiceCategory::DeveloperTools,
+            Self::AppStream | Self::WorkLink | Self::WorkSpaces => {
+                ServiceCategory::EndUserComputing
+            }
+            Self::Amplify
+            | Self::AmplifyBackend
+            | Self::AppSync
+            | Self::DeviceFarm
+            | Self::Location
+            | Self::Mobile => ServiceCategory::FrontEndWebMobile,
+            Self::GameLift => ServiceCategory::GameTech,
+            Self::Greengrass
+            | Self::GreengrassV2
+            | Self::IoT
+            | Self::IoT1clickDevices
+            | Self::IoT1clickProjects
+            | Self::IoTAnalytics
+            | Self::IoTData
+            | Self::IoTDeviceAdvisor
+            | Self::IoTEvents
+            | Self::IoTEventsData
+            | Self::IoTFleetHub
+            | Self::IoTJobsData
+            | Self::IoTSecureTunneling
+            | Self::IoTSitewise
+            | Self::IoTThingsGraph
+            | Self::IoTWireless => ServiceCategory::InternetOfThings,
+            Self::AugmentedAiRuntime
+            | Self::Comprehend
+            | Self::ComprehendMedical
+            | Self::DevOpsGuru
+            | Self::ElasticInference
+            | Self::ForecastQueryService
+            | Self::ForecastService
+            | Self::FraudDetector
+            | Self::HealthLake
+            | Self::Kendra
+            | Self::Lex
+            | Self::LexModels
+            | Self::LexRuntime
+            | Self::LexV2Models
+            | Self::LexV2Runtime
+            | Self::LookoutEquipment
+            | Self::LookoutMetrics
+            | Self::LookoutVision
+            | Self::MachineLearning
+            | Self::Personalize
+            | Self::PersonalizeEvents
+            | Self::PersonalizeRuntime
+            | Self::Polly
+            | Self::Rekognition
+            | Self::SageMaker
+            | Self::SageMakerFeatureStoreRuntime
+            | Self::SageMakerRuntime
+            | Self::SagemakerEdgeManager
+            | Self::Textract
+            | Self::Transcribe
+            | Self::Translate => ServiceCategory::MachineLearning,
+            Self::AppConfig
+            | Self::ApplicationAutoscaling
+            | Self::ApplicationInsights
+            | Self::AutoScalingPlans
+            | Self::CloudFormation
+            | Self::CloudTrail
+            | Self::CloudWatch
+            | Self::CloudWatchLogs
+            | Self::CloudWatchSynthetics
+            | Self::ComputeOptimizer
+            | Self::Config
+            | Self::Health
+            | Self::LicenseManager
+            | Self::OpsWorks
+            | Self::OpsWorksCm
+            | Self::Organizations
+            | Self::Prometheus
+            | Self::ResourceGroups
+            | Self::ResourceGroupsTaggingApi
+            | Self::ServiceCatalog
+            | Self::ServiceCatalogAppRegistry
+            | Self::ServiceQuotas
+            | Self::SimpleSystemsManager
+            | Self::TrustedAdvisor
+            | Self::WellArchitected => ServiceCategory::ManagementGovernance,
+            Self::MarketplaceCatalog
+            | Self::MarketplaceCommerceAnalytics
+            | Self::MarketplaceEntitlement
+            | Self::MarketplaceMetering => ServiceCategory::Marketplace,
+            Self::ElasticTranscoder
+            | Self::InteractiveVideo
+            | Self::KinesisVideo
+            | Self::KinesisVideoArchivedMedia
+            | Self::KinesisVideoMedia
+            | Self::KinesisVideoSignaling
+            | Self::MediaConnect
+            | Self::MediaConvert
+            | Self::MediaLive
+            | Self::MediaPackage
+            | Self::MediaPackageVod
+            | Self::MediaStore
+            | Self::MediaStoreData
+            | Self::MediaTailor => ServiceCategory::MediaServices,
+            Self::ApplicationMigration
+            | Self::DatabaseMigration
+            | Self::DataSync
+            | Self::Discovery
+            | Self::ImportExport
+            | Self::MigrationHub
+            | Self::MigrationHubConfig
+            | Self::ServerMigration
+            | Self::Snowball
+            | Self::Transfer => ServiceCategory::MigrationTransfer,
+            Self::ApiGateway
+            | Self::ApiGatewayExecuteApi
+            | Self::ApiGatewayManagementApi
+            | Self::ApiGatewayV2
+            | Self::AppMesh
+            | Self::CloudFront
+            | Self::DirectConnect
+            | Self::ElasticLoadBalancing
+            | Self::ElasticLoadBalancingV2
+            | Self::Elb
+            | Self::GlobalAccelerator
+            | Self::NetworkManager
+            | Self::Route53
+            | Self::Route53Domains
+            | Self::Route53Resolver
+            | Self::ServiceDiscovery => ServiceCategory::NetworkingContentDelivery,
+            Self::Braket => ServiceCategory::QuantumTechnologies,
+            Self::RoboMaker => ServiceCategory::Robotics,
+            Self::GroundStation => ServiceCategory::Satellite,
+            Self::AccessAnalyzer
+            | Self::Artifact
+            | Self::AuditManager
+            | Self::CertificateManager
+            | Self::CertificateManagerPrivateCa
+            | Self::CloudDirectory
+            | Self::CloudHsm
+            | Self::CloudHsmV2
+            | Self::CognitoIdentity
+            | Self::CognitoIdentityProvider
+            | Self::CognitoSync
+            | Self::Detective
+            | Self::FirewallManagementService
+            | Self::GuardDuty
+            | Self::IdentityAccessManagement
+            | Self::IdentityStore
+            | Self::Inspector
+            | Self::KeyManagement
+            | Self::Macie
+            | Self::Macie2
+            | Self::NetworkFirewall
+            | Self::ResourceAccessManager
+            | Self::SecretsManager
+            | Self::SecurityHub
+            | Self::SecurityToken
+            | Self::Shield
+            | Self::Signer
+            | Self::SingleSignOn
+            | Self::SingleSignOnAdmin
+            | Self::SingleSignOnOpenIdConnect
+            | Self::WebApplicationFirewall
+            | Self::WebApplicationFirewallRegional
+            | Self::WebApplicationFirewallV2 => ServiceCategory::SecurityIdentityCompliance,
+            Self::Backup
+            | Self::DataLifecycleManager
+            | Self::ElasticBlockStore
+            | Self::ElasticFileSystem
+            | Self::Efs
+            | Self::Fsx
+            | Self::Glacier
+            | Self::S3
+            | Self::S3Control
+            | Self::S3Outposts
+            | Self::StorageGateway => ServiceCategory::Storage,
+        }
+    }
+}
+
+/// The top-level groupings of AWS services, as returned by [`Service::category`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ServiceCategory {
+    /// Analytics
+    Analytics,
+    /// Application integration
+    ApplicationIntegration,
+    /// Blockchain
+    Blockchain,
+    /// Business applications
+    BusinessApplications,
+    /// Cloud financial management
+    CloudFinancialManagement,
+    /// Compute
+    Compute,
+    /// Containers
+    Containers,
+    /// Customer enablement
+    CustomerEnablement,
+    /// Customer engagement
+    CustomerEngagement,
+    /// Database
+    Database,
+    /// Developer tools
+    DeveloperTools,
+    /// End-user computing
+    EndUserComputing,
+    /// Front-end web and mobile
+    FrontEndWebMobile,
+    /// Game tech
+    GameTech,
+    /// Internet of Things
+    InternetOfThings,
+    /// Machine learning
+    MachineLearning,
+    /// Management and governance
+    ManagementGovernance,
+    /// AWS Marketplace
+    Marketplace,
+    /// Media services
+    MediaServices,
+    /// Migration and transfer
+    MigrationTransfer,
+    /// Networking and content delivery
+    NetworkingContentDelivery,
+    /// Quantum technologies
+    QuantumTechnologies,
+    /// Robotics
+    Robotics,
+    /// Satellite
+    Satellite,
+    /// Security, identity, and compliance
+    SecurityIdentityCompliance,
+    /// Storage
+    Storage,
+}
+
 fn convert_service_parse_err(s: &str) -> ArnError {
     ArnError::InvalidService(s.to_string())
 }
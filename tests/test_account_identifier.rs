@@ -1,6 +1,7 @@
-use aws_arn::{AccountId, AccountIdentifier, IdentifierLike, ResourceName};
+use aws_arn::{AccountId, AccountIdentifier, ArnError, IdentifierLike, ResourceName};
 use proptest::prelude::*;
 use rstest::rstest;
+use std::convert::TryFrom;
 use std::str::FromStr;
 
 #[rstest]
@@ -69,6 +70,16 @@ fn test_account_id_is_not_valid(#[case] val: &str) {
     assert!(!AccountId::is_valid(val));
 }
 
+#[test]
+fn test_account_id_try_from_string() {
+    let owned = AccountId::try_from("123456789012".to_string()).unwrap();
+    assert_eq!(owned, AccountId::from_str("123456789012").unwrap());
+    assert_eq!(
+        AccountId::try_from("1234".to_string()),
+        Err(ArnError::InvalidAccountId("1234".to_string()))
+    );
+}
+
 proptest! {
     #[test]
     fn proptest_account_id_char_doesnt_crash(s in "\\PC") {
@@ -81,3 +92,27 @@ proptest! {
        assert!(AccountId::from_str(&s).is_ok());
    }
 }
+
+#[rstest]
+#[case(12, "000000000012")]
+#[case(0, "000000000000")]
+#[case(123456789012, "123456789012")]
+#[case(999999999999, "999999999999")]
+fn test_account_id_try_from_u64(#[case] value: u64, #[case] expected: &str) {
+    let account = AccountId::try_from(value).unwrap();
+    assert_eq!(account.to_string(), expected);
+    assert_eq!(account.as_u64(), Some(value));
+}
+
+#[test]
+fn test_account_id_try_from_u64_too_large() {
+    assert_eq!(
+        AccountId::try_from(1_000_000_000_000),
+        Err(ArnError::InvalidAccountId("1000000000000".to_string()))
+    );
+}
+
+#[test]
+fn test_account_id_as_u64_wildcard() {
+    assert_eq!(AccountId::from_str("*").unwrap().as_u64(), None);
+}
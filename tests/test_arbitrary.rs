@@ -0,0 +1,18 @@
+#![cfg(feature = "proptest")]
+
+use aws_arn::ResourceName;
+use proptest::prelude::*;
+use std::str::FromStr;
+
+proptest! {
+    #[test]
+    fn proptest_resource_name_roundtrip(arn in any::<ResourceName>()) {
+        let parsed = ResourceName::from_str(&arn.to_string());
+        prop_assert_eq!(parsed, Ok(arn));
+    }
+
+    #[test]
+    fn proptest_resource_name_is_valid(arn in any::<ResourceName>()) {
+        prop_assert_eq!(arn.validate(), Ok(()), "{}", arn);
+    }
+}
@@ -1,7 +1,10 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::str::FromStr;
 
 use aws_arn::{
-    AccountId, IdentifierLike, Partition, Region, ResourceIdentifier, ResourceName, Service,
+    AccountId, AccountIdentifier, ArnError, ArnResourceFormat, IdentifierLike, PartialResourceName,
+    Partition, Region, ResourceIdentifier, ResourceName, ResourceParts, Service,
 };
 
 fn parse_and_compare(test_arn: &str, expected: ResourceName) {
@@ -13,37 +16,25 @@ fn parse_and_compare(test_arn: &str, expected: ResourceName) {
 
 #[test]
 fn test_valid_arn_to_string() {
-    let arn = ResourceName {
-        partition: Partition::Aws,
-        service: Service::S3,
-        region: None,
-        account_id: None,
-        resource: ResourceIdentifier::new_unchecked("mythings/athing"),
-    };
+    let arn = ResourceName::aws(
+        Service::S3,
+        ResourceIdentifier::new_unchecked("mythings/athing"),
+    );
     assert_eq!(arn.to_string(), "arn:aws:s3:::mythings/athing");
 }
 
 #[test]
 fn test_valid_arn_to_string_wild() {
-    let arn = ResourceName {
-        partition: Partition::Aws,
-        service: Service::S3,
-        region: None,
-        account_id: None,
-        resource: ResourceIdentifier::new_unchecked("mythings/*"),
-    };
+    let arn = ResourceName::aws(Service::S3, ResourceIdentifier::new_unchecked("mythings/*"));
     assert_eq!(arn.to_string(), "arn:aws:s3:::mythings/*");
 }
 
 #[test]
 fn test_valid_arn_to_string_wild_more() {
-    let arn = ResourceName {
-        partition: Partition::Aws,
-        service: Service::S3,
-        region: None,
-        account_id: None,
-        resource: ResourceIdentifier::new_unchecked("mything?/?thing"),
-    };
+    let arn = ResourceName::aws(
+        Service::S3,
+        ResourceIdentifier::new_unchecked("mything?/?thing"),
+    );
     assert_eq!(arn.to_string(), "arn:aws:s3:::mything?/?thing");
 }
 
@@ -51,13 +42,12 @@ fn test_valid_arn_to_string_wild_more() {
 fn test_arn_from_valid_str() {
     parse_and_compare(
         "arn:aws:s3:us-east-1:123456789012:job/23476",
-        ResourceName {
-            partition: Partition::Aws,
-            service: Service::S3,
-            region: Some(Region::UsEast1),
-            account_id: Some(AccountId::new_unchecked("123456789012").into()),
-            resource: ResourceIdentifier::new_unchecked("job/23476"),
-        },
+        ResourceName::regional(
+            Service::S3,
+            Region::UsEast1,
+            AccountId::new_unchecked("123456789012"),
+            ResourceIdentifier::new_unchecked("job/23476"),
+        ),
     );
 }
 
@@ -81,3 +71,856 @@ fn test_github_issues_2() {
     );
     assert!(arn.resource.contains_qualified());
 }
+
+#[test]
+fn test_arn_from_str_colons_in_resource() {
+    let arn = ResourceName::from_str(
+        "arn:aws:logs:us-east-1:123456789012:log-group:my-group:log-stream:a:b:c",
+    )
+    .unwrap();
+    assert_eq!(
+        arn.resource,
+        ResourceIdentifier::new_unchecked("log-group:my-group:log-stream:a:b:c")
+    );
+}
+
+#[test]
+fn test_arn_from_str_empty_resource() {
+    let result = ResourceName::from_str("arn:aws:sns:us-east-1:123456789012:");
+    assert_eq!(result, Err(ArnError::MissingResource));
+}
+
+#[test]
+fn test_validate_valid_arns() {
+    for arn in [
+        "arn:aws:iam::123456789012:user/Bob",
+        "arn:aws:lambda:us-east-2:123456789012:layer:my-layer:3",
+        "arn:aws:s3:::my-bucket",
+        "arn:aws:s3:us-east-1:123456789012:job/23476",
+        "arn:aws:iam::aws:policy/ReadOnlyAccess",
+        "arn:aws:cloudfront::123456789012:distribution/E123",
+        "arn:aws:iam:aws-global:123456789012:user/Bob",
+    ] {
+        let arn = ResourceName::from_str(arn).unwrap();
+        assert_eq!(arn.validate(), Ok(()), "{}", arn);
+    }
+}
+
+#[test]
+fn test_try_from_parts() {
+    let arn = ResourceName::try_from([
+        "arn",
+        "aws",
+        "lambda",
+        "us-east-2",
+        "123456789012",
+        "function:my-function",
+    ])
+    .unwrap();
+    assert_eq!(
+        arn,
+        ResourceName::from_str("arn:aws:lambda:us-east-2:123456789012:function:my-function")
+            .unwrap()
+    );
+    assert_eq!(arn.source(), None);
+}
+
+#[test]
+fn test_try_from_parts_bad_service() {
+    let error = ResourceName::try_from(["arn", "aws", "nope", "", "", "my-bucket"]).unwrap_err();
+    assert_eq!(error.component_index(), Some(2));
+    assert_eq!(
+        ResourceName::try_from(["nra", "aws", "s3", "", "", "my-bucket"]),
+        Err(ArnError::MissingPrefix)
+    );
+}
+
+#[test]
+fn test_parse_partial_bad_region() {
+    let (partial, error) =
+        ResourceName::parse_partial("arn:aws:sqs:us-nowhere-1:123456789012:queue");
+    assert_eq!(partial.partition, Some(Partition::Aws));
+    assert_eq!(partial.service, Some(Service::SimpleQueue));
+    assert_eq!(partial.region, None);
+    assert_eq!(partial.account_id, None);
+    assert_eq!(partial.resource, None);
+    let error = error.unwrap();
+    assert_eq!(error.component_index(), Some(3));
+    assert!(error.to_string().contains("region"), "{}", error);
+}
+
+#[test]
+fn test_parse_partial_incomplete() {
+    let (partial, error) = ResourceName::parse_partial("arn:aws:lambda:us-east-1");
+    assert_eq!(partial.region, Some(Region::UsEast1));
+    assert_eq!(error, Some(ArnError::TooFewComponents(4)));
+}
+
+#[test]
+fn test_parse_partial_same_error_as_from_str() {
+    for input in &[
+        "arn",
+        "arn:aws:s3",
+        "arn:nowhere:s3:::my-bucket",
+        "arn:aws:lambda:us-east-1:123456789012:",
+        "arn:aws:sqs:us-east-1:123456789012:a\u{7}",
+    ] {
+        let (_, error) = ResourceName::parse_partial(input);
+        assert_eq!(error, ResourceName::from_str(input).err(), "{:?}", input);
+    }
+}
+
+#[test]
+fn test_from_str_counts_components_before_prefix() {
+    for (input, count) in &[("", 1), ("foo", 1), ("aws:s3:::x", 5)] {
+        assert_eq!(
+            ResourceName::from_str(input),
+            Err(ArnError::TooFewComponents(*count)),
+            "{:?}",
+            input
+        );
+    }
+    let (_, error) = ResourceName::parse_partial("foo");
+    assert_eq!(error, Some(ArnError::MissingPrefix));
+}
+
+#[test]
+fn test_parse_partial_complete() {
+    let input = "arn:aws:lambda:us-east-1:123456789012:function:my-function";
+    let (partial, error) = ResourceName::parse_partial(input);
+    assert_eq!(error, None);
+    let arn = ResourceName::from_str(input).unwrap();
+    assert_eq!(
+        partial,
+        PartialResourceName {
+            partition: Some(arn.partition),
+            service: Some(arn.service),
+            region: arn.region,
+            account_id: arn.account_id,
+            resource: Some(arn.resource),
+        }
+    );
+}
+
+#[test]
+fn test_parse_strict_matching_partition() {
+    for arn in [
+        "arn:aws:sqs:us-east-1:123456789012:my-queue",
+        "arn:aws-cn:sqs:cn-north-1:123456789012:my-queue",
+        "arn:aws-us-gov:sqs:us-gov-west-1:123456789012:my-queue",
+        "arn:aws:s3:::my-bucket",
+    ] {
+        assert_eq!(
+            ResourceName::parse_strict(arn),
+            Ok(ResourceName::from_str(arn).unwrap()),
+            "{}",
+            arn
+        );
+    }
+}
+
+#[test]
+fn test_parse_strict_mismatched_partition() {
+    let input = "arn:aws:s3:us-gov-west-1:123456789012:job/23476";
+    assert!(ResourceName::from_str(input).is_ok());
+    assert_eq!(
+        ResourceName::parse_strict(input),
+        Err(ArnError::PartitionRegionMismatch {
+            partition: "aws".to_string(),
+            region: "us-gov-west-1".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_validate_global_service_with_region() {
+    let arn = ResourceName::from_str("arn:aws:iam:us-east-1:123456789012:user/Bob").unwrap();
+    assert_eq!(
+        arn.validate(),
+        Err(ArnError::RegionNotAllowed("iam".to_string()))
+    );
+}
+
+#[test]
+fn test_parse_global_pseudo_region() {
+    let input = "arn:aws:cloudfront:aws-global:123456789012:distribution/E123";
+    let arn = ResourceName::from_str(input).unwrap();
+    assert_eq!(arn.service, Service::CloudFront);
+    assert_eq!(arn.region, Some(Region::AwsGlobal));
+    assert_eq!(arn.to_string(), input);
+}
+
+#[test]
+fn test_validate_regional_service_without_region() {
+    let arn = ResourceName::from_str("arn:aws:lambda::123456789012:function:my-function").unwrap();
+    assert_eq!(arn.validate(), Err(ArnError::MissingRegion));
+}
+
+#[test]
+fn test_validate_bad_account_id() {
+    let arn = ResourceName::from_str("arn:aws:s3:us-east-1:123456789012:job/23476")
+        .unwrap()
+        .with_account(Some(AccountId::new_unchecked("1234").into()));
+    assert_eq!(
+        arn.validate(),
+        Err(ArnError::InvalidAccountId("1234".to_string()))
+    );
+}
+
+#[test]
+fn test_redacted() {
+    let arn = ResourceName::from_str("arn:aws:lambda:us-east-2:123456789012:function:my-function")
+        .unwrap();
+    let redacted = arn.redacted();
+    assert_eq!(
+        redacted,
+        "arn:aws:lambda:us-east-2:REDACTED:function:my-function"
+    );
+    assert!(!redacted.contains("123456789012"));
+
+    let bucket = ResourceName::from_str("arn:aws:s3:::my-bucket").unwrap();
+    assert_eq!(bucket.redacted(), "arn:aws:s3:::my-bucket");
+}
+
+#[test]
+fn test_without_account() {
+    let arn = ResourceName::from_str("arn:aws:sqs:us-east-1:123456789012:my-queue")
+        .unwrap()
+        .without_account();
+    assert_eq!(arn.account_id, None);
+    assert_eq!(arn.to_string(), "arn:aws:sqs:us-east-1::my-queue");
+}
+
+#[test]
+fn test_byte_len() {
+    for arn in [
+        "arn:aws:s3:::my-bucket",
+        "arn:aws:lambda:us-east-2:123456789012:function:my-function",
+        "arn:aws:iam::aws:policy/ReadOnlyAccess",
+        "arn:aws:s3:::my-bucket/München",
+    ] {
+        let arn = ResourceName::from_str(arn).unwrap();
+        assert_eq!(arn.byte_len(), arn.to_string().len(), "{}", arn);
+    }
+}
+
+#[test]
+fn test_validate_too_long() {
+    let arn = ResourceName::from_str("arn:aws:s3:::my-bucket")
+        .unwrap()
+        .with_resource(ResourceIdentifier::from_str(&"a".repeat(3000)).unwrap());
+    assert_eq!(arn.validate(), Err(ArnError::TooLong(3013)));
+}
+
+#[test]
+fn test_validate_length_counts_characters() {
+    let arn = ResourceName::from_str("arn:aws:s3:::my-bucket")
+        .unwrap()
+        .with_resource(ResourceIdentifier::from_str(&"é".repeat(2000)).unwrap());
+    assert!(arn.byte_len() > 2048);
+    assert_eq!(arn.validate(), Ok(()));
+
+    let arn = arn.with_resource(ResourceIdentifier::from_str(&"é".repeat(2040)).unwrap());
+    assert_eq!(arn.validate(), Err(ArnError::TooLong(2053)));
+}
+
+#[test]
+fn test_validate_missing_resource() {
+    let arn = ResourceName::from_str("arn:aws:s3:::my-bucket")
+        .unwrap()
+        .with_resource(ResourceIdentifier::new_unchecked(""));
+    assert!(Service::S3.requires_resource());
+    assert_eq!(arn.validate(), Err(ArnError::MissingResource));
+}
+
+#[test]
+fn test_validate_account_wildcard_allowed() {
+    for arn in &[
+        "arn:aws:sqs:us-east-1:*:my-queue",
+        "arn:aws:iam::*:role/Admin",
+    ] {
+        assert_eq!(
+            ResourceName::from_str(arn).unwrap().validate(),
+            Ok(()),
+            "{}",
+            arn
+        );
+    }
+}
+
+#[test]
+fn test_validate_principal_account_wildcard_not_allowed() {
+    let arn = ResourceName::from_str("arn:aws:iam::*:role/Admin").unwrap();
+    assert_eq!(
+        arn.validate_principal(),
+        Err(ArnError::AccountIdWildcardNotAllowed("iam".to_string()))
+    );
+    assert_eq!(
+        arn.validate_principal().unwrap_err().to_string(),
+        "account wildcards are not allowed in a principal ARN (service iam)"
+    );
+
+    let arn = ResourceName::from_str("arn:aws:iam::123456789012:role/Admin").unwrap();
+    assert_eq!(arn.validate_principal(), Ok(()));
+}
+
+#[test]
+fn test_expand_accounts() {
+    let accounts: Vec<AccountId> = ["111111111111", "222222222222", "333333333333"]
+        .iter()
+        .map(|s| AccountId::from_str(s).unwrap())
+        .collect();
+    let template = ResourceName::from_str("arn:aws:sqs:us-east-1:*:queue-${account}").unwrap();
+    let arns: Vec<String> = template
+        .expand_accounts(&accounts)
+        .unwrap()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        arns,
+        vec![
+            "arn:aws:sqs:us-east-1:111111111111:queue-111111111111",
+            "arn:aws:sqs:us-east-1:222222222222:queue-222222222222",
+            "arn:aws:sqs:us-east-1:333333333333:queue-333333333333",
+        ]
+    );
+}
+
+#[test]
+fn test_expand_accounts_variable_account() {
+    let template = ResourceName::from_str("arn:aws:sqs:us-east-1:${account}:queue").unwrap();
+    let arns = template
+        .expand_accounts(&[AccountId::from_str("111111111111").unwrap()])
+        .unwrap();
+    assert_eq!(
+        arns[0].to_string(),
+        "arn:aws:sqs:us-east-1:111111111111:queue"
+    );
+}
+
+#[test]
+fn test_expand_accounts_no_placeholder() {
+    let arn = ResourceName::from_str("arn:aws:sqs:us-east-1:123456789012:queue").unwrap();
+    assert_eq!(
+        arn.expand_accounts(&[AccountId::from_str("111111111111").unwrap()]),
+        Err(ArnError::MissingAccountPlaceholder)
+    );
+}
+
+#[test]
+fn test_map_resource() {
+    let arn = ResourceName::from_str("arn:aws:s3:::my-bucket/site").unwrap();
+    let arn = arn
+        .map_resource(|resource| resource.join_path(&ResourceIdentifier::from_str("index.html")?))
+        .unwrap();
+    assert_eq!(arn.to_string(), "arn:aws:s3:::my-bucket/site/index.html");
+    assert_eq!(arn.source(), None);
+}
+
+#[test]
+fn test_map_resource_error() {
+    let arn = ResourceName::from_str("arn:aws:s3:::my-bucket/site").unwrap();
+    assert_eq!(
+        arn.clone()
+            .map_resource(|resource| ResourceIdentifier::from_str(&format!("{}\t", resource))),
+        Err(ArnError::InvalidResourceCharacter('\t'))
+    );
+    assert_eq!(
+        arn.map_resource(|_| Ok(ResourceIdentifier::new_unchecked(""))),
+        Err(ArnError::InvalidResource("".to_string()))
+    );
+}
+
+#[test]
+fn test_with_region() {
+    let arn = ResourceName::from_str("arn:aws:lambda:us-east-1:123456789012:function:my-function")
+        .unwrap()
+        .with_region(Some(Region::EuWest1));
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:lambda:eu-west-1:123456789012:function:my-function"
+    );
+}
+
+#[test]
+fn test_with_region_fixing_partition() {
+    let arn = ResourceName::from_str("arn:aws:lambda:us-east-1:123456789012:function:my-function")
+        .unwrap()
+        .with_region_fixing_partition(Region::UsGovWest1);
+    assert_eq!(arn.partition, Partition::AwsUsGov);
+    assert_eq!(arn.region, Some(Region::UsGovWest1));
+    assert_eq!(arn.validate(), Ok(()));
+
+    let arn = arn.with_region_fixing_partition(Region::EuWest1);
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:lambda:eu-west-1:123456789012:function:my-function"
+    );
+}
+
+#[test]
+fn test_with_account_removed() {
+    let arn = ResourceName::from_str("arn:aws:s3:us-east-1:123456789012:job/23476")
+        .unwrap()
+        .with_account(None);
+    assert_eq!(arn.to_string(), "arn:aws:s3:us-east-1::job/23476");
+}
+
+#[test]
+fn test_normalize_path() {
+    let arn = ResourceName::from_str("arn:aws:s3:::a//b/").unwrap();
+    assert_eq!(arn.normalize_path().to_string(), "arn:aws:s3:::a/b");
+
+    let arn = ResourceName::from_str("arn:aws:apigateway:us-east-1::/restapis//abc123/").unwrap();
+    assert_eq!(
+        arn.normalize_path().resource.to_string(),
+        "/restapis/abc123"
+    );
+
+    let arn = ResourceName::from_str("arn:aws:s3:::my-bucket/logs/2024").unwrap();
+    let normalized = arn.clone().normalize_path();
+    assert_eq!(normalized, arn);
+    assert_eq!(normalized.source(), arn.source());
+}
+
+#[test]
+fn test_normalize_path_not_automatic() {
+    // S3 keys may contain empty segments, so parsing and display leave them alone.
+    let arn = ResourceName::from_str("arn:aws:s3:::my-bucket/logs//2024/").unwrap();
+    assert_eq!(arn.resource.to_string(), "my-bucket/logs//2024/");
+    assert_eq!(arn.to_string(), "arn:aws:s3:::my-bucket/logs//2024/");
+}
+
+#[test]
+fn test_with_partition_and_resource() {
+    let arn = ResourceName::from_str("arn:aws:s3:::my-bucket")
+        .unwrap()
+        .with_partition(Partition::AwsChina)
+        .with_resource(ResourceIdentifier::new_unchecked("other-bucket"));
+    assert_eq!(arn.to_string(), "arn:aws-cn:s3:::other-bucket");
+}
+
+#[test]
+fn test_service_namespace() {
+    let arn = ResourceName::from_str("arn:aws:sqs:us-east-1:123456789012:queue").unwrap();
+    assert_eq!(arn.service_namespace(), "sqs");
+}
+
+#[test]
+fn test_components() {
+    let arn = ResourceName::from_str("arn:aws:s3:us-east-1:123456789012:job/23476").unwrap();
+    let components = arn.components();
+    assert_eq!(components.partition, "aws");
+    assert_eq!(components.service, "s3");
+    assert_eq!(components.region, Some("us-east-1"));
+    assert_eq!(components.account, Some("123456789012"));
+    assert_eq!(components.resource, "job/23476");
+
+    let arn = ResourceName::from_str("arn:aws:s3:::my-bucket").unwrap();
+    let components = arn.components();
+    assert_eq!(components.region, None);
+    assert_eq!(components.account, None);
+}
+
+#[test]
+fn test_eq_ignore_ascii_case() {
+    let lhs = ResourceName::from_str("arn:aws:iam::123456789012:user/Bob").unwrap();
+    let rhs = ResourceName::from_str("arn:aws:iam::123456789012:USER/bob").unwrap();
+    assert!(lhs.eq_ignore_ascii_case(&rhs));
+    assert_ne!(lhs, rhs);
+
+    let other_account = ResourceName::from_str("arn:aws:iam::210987654321:user/Bob").unwrap();
+    assert!(!lhs.eq_ignore_ascii_case(&other_account));
+    let no_account = ResourceName::from_str("arn:aws:iam:::user/Bob").unwrap();
+    assert!(!lhs.eq_ignore_ascii_case(&no_account));
+    let service_account = ResourceName::from_str("arn:aws:iam::aws:policy/ReadOnlyAccess").unwrap();
+    let service_account_upper =
+        ResourceName::from_str("arn:aws:iam::AWS:policy/readonlyaccess").unwrap();
+    assert!(service_account.eq_ignore_ascii_case(&service_account_upper));
+}
+
+#[test]
+fn test_is_aws_managed() {
+    let managed = ResourceName::from_str("arn:aws:iam::aws:policy/ReadOnlyAccess").unwrap();
+    assert!(managed.is_aws_managed());
+    let customer =
+        ResourceName::from_str("arn:aws:iam::123456789012:policy/ReadOnlyAccess").unwrap();
+    assert!(!customer.is_aws_managed());
+    let no_account = ResourceName::from_str("arn:aws:s3:::my-bucket").unwrap();
+    assert!(!no_account.is_aws_managed());
+}
+
+#[test]
+fn test_has_wildcards_and_is_fully_qualified() {
+    let concrete = ResourceName::from_str("arn:aws:sqs:us-east-1:123456789012:queue").unwrap();
+    assert!(!concrete.has_wildcards());
+    assert!(concrete.is_fully_qualified());
+
+    let resource_wildcard =
+        ResourceName::from_str("arn:aws:sqs:us-east-1:123456789012:queue-?").unwrap();
+    assert!(resource_wildcard.has_wildcards());
+    assert!(!resource_wildcard.is_fully_qualified());
+
+    let account_wildcard = ResourceName::from_str("arn:aws:sqs:us-east-1:*:queue").unwrap();
+    assert!(account_wildcard.has_wildcards());
+    assert!(!account_wildcard.is_fully_qualified());
+
+    let no_region = ResourceName::from_str("arn:aws:s3:::my-bucket").unwrap();
+    assert!(!no_region.has_wildcards());
+    assert!(!no_region.is_fully_qualified());
+}
+
+#[test]
+fn test_source_preserved() {
+    let input = "arn:aws:SQS:us-west-2:123456789012:my-queue";
+    let arn = ResourceName::from_str(input).unwrap();
+    assert_eq!(arn.source(), Some(input));
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:sqs:us-west-2:123456789012:my-queue"
+    );
+
+    let canonical = ResourceName::from_str(&arn.to_string()).unwrap();
+    assert_eq!(arn, canonical);
+    assert_ne!(arn.source(), canonical.source());
+
+    let modified = arn.with_region(Some(Region::UsEast1));
+    assert_eq!(modified.source(), None);
+}
+
+#[test]
+fn test_parse_trimmed() {
+    for input in &[
+        " arn:aws:s3:::my-bucket",
+        "arn:aws:s3:::my-bucket \n",
+        "\t arn:aws:s3:::my-bucket\r\n",
+    ] {
+        let arn = ResourceName::parse_trimmed(input).unwrap();
+        assert_eq!(arn.to_string(), "arn:aws:s3:::my-bucket");
+    }
+    assert!(ResourceName::from_str(" arn:aws:s3:::my-bucket").is_err());
+}
+
+#[test]
+fn test_parse_trimmed_internal_space() {
+    assert_eq!(
+        ResourceName::parse_trimmed(" arn:aws:s 3:::my-bucket "),
+        Err(ArnError::Component {
+            index: 2,
+            source: Box::new(ArnError::InvalidService("s 3".to_string()))
+        })
+    );
+}
+
+#[test]
+fn test_resource_without_type() {
+    for (arn, expected) in &[
+        ("arn:aws:iam::123456789012:user/Bob", Some("Bob")),
+        (
+            "arn:aws:lambda:us-east-1:123456789012:layer:name:3",
+            Some("name:3"),
+        ),
+        ("arn:aws:sns:us-east-1:123456789012:plain", None),
+    ] {
+        let arn = ResourceName::from_str(arn).unwrap();
+        assert_eq!(arn.resource_without_type(), *expected);
+    }
+}
+
+#[test]
+fn test_string_round_trip() {
+    let input = "arn:aws:lambda:us-east-1:123456789012:function:my-function";
+    let arn = ResourceName::try_from_sdk(input).unwrap();
+    assert_eq!(String::from(&arn), input);
+    let s: String = arn.clone().into();
+    assert_eq!(s, input);
+    assert_eq!(ResourceName::from_str(&s).unwrap(), arn);
+}
+
+#[test]
+fn test_getters() {
+    let arn = ResourceName::from_str("arn:aws:s3:us-east-1:123456789012:job/23476").unwrap();
+    assert_eq!(arn.partition(), &Partition::Aws);
+    assert_eq!(arn.service(), &Service::S3);
+    assert_eq!(arn.region(), Some(&Region::UsEast1));
+    assert_eq!(
+        arn.account_id(),
+        Some(&AccountId::new_unchecked("123456789012").into())
+    );
+    assert_eq!(
+        arn.resource(),
+        &ResourceIdentifier::new_unchecked("job/23476")
+    );
+}
+
+#[test]
+fn test_resource_type() {
+    for (arn, expected) in &[
+        ("arn:aws:iam::123456789012:user/Bob", Some("user")),
+        (
+            "arn:aws:lambda:us-east-1:123456789012:layer:name:3",
+            Some("layer"),
+        ),
+        ("arn:aws:sns:us-east-1:123456789012:plain", None),
+    ] {
+        let arn = ResourceName::from_str(arn).unwrap();
+        assert_eq!(arn.resource_type(), *expected);
+    }
+}
+
+#[test]
+fn test_resource_type_with_hyphens() {
+    let arn = ResourceName::from_str(
+        "arn:aws:lambda:us-east-1:123456789012:event-source-mapping:fa5ae1f9-c3a5-4b8e-a6a6-1c1d6b1e0f2a",
+    )
+    .unwrap();
+    assert_eq!(arn.resource_type(), Some("event-source-mapping"));
+    assert_eq!(
+        arn.resource_without_type(),
+        Some("fa5ae1f9-c3a5-4b8e-a6a6-1c1d6b1e0f2a")
+    );
+}
+
+#[test]
+fn test_parse_lines() {
+    let input = "\
+# Example ARNs
+arn:aws:s3:::my-bucket
+
+arn:aws:sqs:us-east-1:123456789012:my-queue
+arn:aws:s3
+";
+    let results: Vec<(usize, Result<ResourceName, ArnError>)> =
+        ResourceName::parse_lines(input).collect();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].0, 2);
+    assert_eq!(results[0].1.as_ref().unwrap().service, Service::S3);
+    assert_eq!(results[1].0, 4);
+    assert_eq!(results[1].1.as_ref().unwrap().service, Service::SimpleQueue);
+    assert_eq!(results[2], (5, Err(ArnError::TooFewComponents(3))));
+}
+
+#[test]
+fn test_from_str_component_index() {
+    let bad_service =
+        ResourceName::from_str("arn:aws:nope:us-east-1:123456789012:queue").unwrap_err();
+    assert_eq!(bad_service.component_index(), Some(2));
+
+    let bad_region =
+        ResourceName::from_str("arn:aws:sqs:us-nowhere-1:123456789012:queue").unwrap_err();
+    assert_eq!(bad_region.component_index(), Some(3));
+    assert_eq!(
+        bad_region,
+        ArnError::Component {
+            index: 3,
+            source: Box::new(ArnError::InvalidRegion("us-nowhere-1".to_string()))
+        }
+    );
+    assert_eq!(bad_region.to_string(), "component 3 (region) is invalid");
+
+    let bad_resource =
+        ResourceName::from_str("arn:aws:sqs:us-east-1:123456789012:a\u{7}").unwrap_err();
+    assert_eq!(bad_resource.component_index(), Some(5));
+
+    let too_short = ResourceName::from_str("arn:aws:sqs").unwrap_err();
+    assert_eq!(too_short.component_index(), None);
+}
+
+#[test]
+fn test_resource_name_as_map_key() {
+    let mut policies: HashMap<ResourceName, &str> = HashMap::new();
+    policies.insert(
+        ResourceName::from_str("arn:aws:SQS:us-west-2:123456789012:my-queue").unwrap(),
+        "first",
+    );
+    policies.insert(
+        ResourceName::from_str("arn:aws:sqs:us-west-2:123456789012:my-queue").unwrap(),
+        "second",
+    );
+    assert_eq!(policies.len(), 1);
+    assert_eq!(policies.values().next(), Some(&"second"));
+}
+
+#[test]
+fn test_regional_constructor() {
+    let arn = ResourceName::regional(
+        Service::Lambda,
+        Region::UsEast2,
+        AccountId::from_str("123456789012").unwrap(),
+        ResourceIdentifier::from_str("function:my-function").unwrap(),
+    );
+    assert_eq!(
+        arn,
+        ResourceName::from_str("arn:aws:lambda:us-east-2:123456789012:function:my-function")
+            .unwrap()
+    );
+    assert_eq!(arn.validate(), Ok(()));
+}
+
+#[test]
+fn test_regional_constructor_partition_from_region() {
+    let arn = ResourceName::regional(
+        Service::SimpleQueue,
+        Region::UsGovWest1,
+        AccountId::from_str("123456789012").unwrap(),
+        ResourceIdentifier::from_str("my-queue").unwrap(),
+    );
+    assert_eq!(arn.partition, Partition::AwsUsGov);
+}
+
+#[test]
+fn test_new_is_aws() {
+    let resource = ResourceIdentifier::from_str("my-bucket").unwrap();
+    assert_eq!(
+        ResourceName::new(Service::S3, resource.clone()),
+        ResourceName::aws(Service::S3, resource)
+    );
+}
+
+#[test]
+fn test_for_service() {
+    let bucket = ResourceName::for_service(Service::S3, "my-bucket").unwrap();
+    assert_eq!(bucket.to_string(), "arn:aws:s3:::my-bucket");
+    assert_eq!(
+        bucket,
+        ResourceName::aws(Service::S3, ResourceIdentifier::new_unchecked("my-bucket"))
+    );
+
+    let zone = ResourceName::for_service(Service::Route53, "hostedzone/Z123").unwrap();
+    assert_eq!(zone.to_string(), "arn:aws:route53:::hostedzone/Z123");
+}
+
+#[test]
+fn test_for_service_invalid() {
+    assert_eq!(
+        ResourceName::for_service(Service::Lambda, "function:my-function"),
+        Err(ArnError::MissingRegion)
+    );
+    assert_eq!(
+        ResourceName::for_service(Service::S3, ""),
+        Err(ArnError::InvalidResource(String::new()))
+    );
+}
+
+#[test]
+fn test_display_alternate() {
+    let arn = ResourceName::from_str("arn:aws:lambda:us-east-2:123456789012:function:my-function")
+        .unwrap();
+    assert_eq!(
+        format!("{}", arn),
+        "arn:aws:lambda:us-east-2:123456789012:function:my-function"
+    );
+    assert_eq!(
+        format!("{:#}", arn),
+        "partition: aws\n\
+         service:   lambda\n\
+         region:    us-east-2\n\
+         account:   123456789012\n\
+         resource:  function:my-function"
+    );
+}
+
+#[test]
+fn test_display_alternate_missing_components() {
+    let arn = ResourceName::from_str("arn:aws:s3:::my-bucket").unwrap();
+    assert!(format!("{:#}", arn).contains("region:    -\naccount:   -\n"));
+}
+
+#[test]
+fn test_same_resource() {
+    let bucket = ResourceName::from_str("arn:aws:s3:::my-bucket").unwrap();
+    let copy = bucket.clone().with_account(Some(AccountIdentifier::Account(
+        AccountId::from_str("123456789012").unwrap(),
+    )));
+    assert!(bucket.same_resource(&copy));
+    assert_ne!(bucket, copy);
+
+    let other = ResourceName::from_str("arn:aws:s3:::other-bucket").unwrap();
+    assert!(!bucket.same_resource(&other));
+
+    let china = bucket.clone().with_partition(Partition::AwsChina);
+    assert!(!bucket.same_resource(&china));
+}
+
+#[test]
+fn test_arn_format() {
+    for (arn, expected) in [
+        ("arn:aws:s3:::my-bucket", ArnResourceFormat::Id),
+        (
+            "arn:aws:iam::123456789012:user/Bob",
+            ArnResourceFormat::TypeSlashId,
+        ),
+        (
+            "arn:aws:lambda:us-east-1:123456789012:function:my-function",
+            ArnResourceFormat::TypeColonId,
+        ),
+        (
+            "arn:aws:ecs:us-east-1:123456789012:task-definition/my-family:7",
+            ArnResourceFormat::TypeSlashId,
+        ),
+    ] {
+        assert_eq!(
+            ResourceName::from_str(arn).unwrap().arn_format(),
+            expected,
+            "{}",
+            arn
+        );
+    }
+}
+
+#[test]
+fn test_resource_parts() {
+    for (arn, type_, id, qualifier) in [
+        ("arn:aws:s3:::my-bucket", None, "my-bucket", None),
+        (
+            "arn:aws:iam::123456789012:user/division_abc/Bob",
+            Some("user"),
+            "division_abc/Bob",
+            None,
+        ),
+        (
+            "arn:aws:lambda:us-east-1:123456789012:function:my-function",
+            Some("function"),
+            "my-function",
+            None,
+        ),
+        (
+            "arn:aws:lambda:us-east-1:123456789012:layer:my-layer:3",
+            Some("layer"),
+            "my-layer",
+            Some("3"),
+        ),
+        (
+            "arn:aws:ecs:us-east-1:123456789012:task-definition/my-family:7",
+            Some("task-definition"),
+            "my-family",
+            Some("7"),
+        ),
+    ] {
+        assert_eq!(
+            ResourceName::from_str(arn).unwrap().resource_parts(),
+            ResourceParts {
+                type_,
+                id,
+                qualifier
+            },
+            "{}",
+            arn
+        );
+    }
+}
+
+#[test]
+fn test_metric_key() {
+    for (arn, expected) in &[
+        ("arn:aws:s3:::my-bucket", "s3"),
+        ("arn:aws:iam::123456789012:user/Bob", "iam:user"),
+        (
+            "arn:aws:lambda:us-east-1:123456789012:function:my-function:3",
+            "lambda:function",
+        ),
+    ] {
+        assert_eq!(ResourceName::from_str(arn).unwrap().metric_key(), *expected);
+    }
+}
@@ -1,4 +1,6 @@
-use aws_arn::{AccountId, Identifier, Region, ResourceIdentifier, ResourceName, Service};
+#![cfg(feature = "builders")]
+
+use aws_arn::{AccountId, ArnError, Identifier, Region, ResourceIdentifier, ResourceName, Service};
 use std::str::FromStr;
 
 #[test]
@@ -28,3 +30,37 @@ fn test_lambda_layer() {
         "arn:aws:lambda:us-east-2:123456789012:layer:my-layer:3"
     );
 }
+
+#[test]
+fn test_try_build_iam_with_region() {
+    let result = ResourceName::builder()
+        .service(Service::IdentityAccessManagement)
+        .in_region(Region::UsEast1)
+        .owned_by(AccountId::from_str("123456789012").unwrap())
+        .resource(ResourceIdentifier::from_str("user/Bob").unwrap())
+        .try_build();
+    assert_eq!(result, Err(ArnError::RegionNotAllowed("iam".to_string())));
+}
+
+#[test]
+fn test_try_build_iam_without_region() {
+    let result = ResourceName::builder()
+        .service(Service::IdentityAccessManagement)
+        .owned_by(AccountId::from_str("123456789012").unwrap())
+        .resource(ResourceIdentifier::from_str("user/Bob").unwrap())
+        .try_build();
+    assert_eq!(
+        result.map(|arn| arn.to_string()),
+        Ok("arn:aws:iam::123456789012:user/Bob".to_string())
+    );
+}
+
+#[test]
+fn test_try_build_lambda_missing_region() {
+    let result = ResourceName::builder()
+        .service(Service::Lambda)
+        .owned_by(AccountId::from_str("123456789012").unwrap())
+        .resource(ResourceIdentifier::from_str("function:my-function").unwrap())
+        .try_build();
+    assert_eq!(result, Err(ArnError::MissingRegion));
+}
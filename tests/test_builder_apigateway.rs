@@ -0,0 +1,55 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::apigateway;
+use aws_arn::{
+    AccountId, Identifier, IdentifierLike, Partition, Region, ResourceIdentifier, ResourceName,
+};
+use std::str::FromStr;
+
+#[test]
+fn test_apigateway_rest_api() {
+    let arn = apigateway::rest_api(
+        Partition::Aws,
+        Region::UsEast1,
+        Identifier::new_unchecked("a1b2c3"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:apigateway:us-east-1::/restapis/a1b2c3"
+    );
+    assert_eq!(arn.resource.to_string(), "/restapis/a1b2c3");
+    assert_eq!(arn.account_id, None);
+    assert_eq!(ResourceName::from_str(&arn.to_string()), Ok(arn));
+}
+
+#[test]
+fn test_apigateway_http_api() {
+    let arn = apigateway::http_api(
+        Partition::Aws,
+        Region::EuWest1,
+        Identifier::new_unchecked("a1b2c3"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:apigateway:eu-west-1::/apis/a1b2c3"
+    );
+    assert_eq!(ResourceName::from_str(&arn.to_string()), Ok(arn));
+}
+
+#[test]
+fn test_apigateway_invoke() {
+    let arn = apigateway::invoke(
+        Partition::Aws,
+        Region::UsEast1,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("a1b2c3"),
+        Identifier::new_unchecked("prod"),
+        Identifier::new_unchecked("GET"),
+        ResourceIdentifier::new_unchecked("pets/*"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:execute-api:us-east-1:123456789012:a1b2c3/prod/GET/pets/*"
+    );
+    assert_eq!(ResourceName::from_str(&arn.to_string()), Ok(arn));
+}
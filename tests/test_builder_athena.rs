@@ -0,0 +1,32 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::athena;
+use aws_arn::{AccountId, Identifier, IdentifierLike, Partition, Region};
+
+#[test]
+fn test_athena_workgroup() {
+    let arn = athena::workgroup(
+        Partition::Aws,
+        Region::UsEast1,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("primary"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:athena:us-east-1:123456789012:workgroup/primary"
+    );
+}
+
+#[test]
+fn test_athena_data_catalog() {
+    let arn = athena::data_catalog(
+        Partition::Aws,
+        Region::UsWest2,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("my-catalog"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:athena:us-west-2:123456789012:datacatalog/my-catalog"
+    );
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::cloudformation;
+use aws_arn::{AccountId, Identifier, IdentifierLike, Partition, Region, ResourceName};
+use std::str::FromStr;
+
+fn account() -> AccountId {
+    AccountId::new_unchecked("123456789012")
+}
+
+#[test]
+fn test_cloudformation_stack() {
+    let arn = cloudformation::stack(
+        Partition::Aws,
+        Region::UsEast1,
+        account(),
+        Identifier::new_unchecked("my-stack"),
+        Identifier::new_unchecked("8c5e5a50-0b0b-11ef-a1b2-0a1b2c3d4e5f"),
+    );
+    let expected = "arn:aws:cloudformation:us-east-1:123456789012:stack/my-stack/8c5e5a50-0b0b-11ef-a1b2-0a1b2c3d4e5f";
+    assert_eq!(arn.to_string(), expected);
+    assert_eq!(ResourceName::from_str(expected).unwrap(), arn);
+}
+
+#[test]
+fn test_cloudformation_change_set() {
+    let arn = cloudformation::change_set(
+        Partition::Aws,
+        Region::EuWest1,
+        account(),
+        Identifier::new_unchecked("my-change-set"),
+        Identifier::new_unchecked("1a2b3c4d-5e6f-7a8b-9c0d-1e2f3a4b5c6d"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:cloudformation:eu-west-1:123456789012:changeSet/my-change-set/1a2b3c4d-5e6f-7a8b-9c0d-1e2f3a4b5c6d"
+    );
+}
@@ -0,0 +1,18 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::cloudwatch;
+use aws_arn::{AccountId, IdentifierLike, Partition, Region, ResourceIdentifier, ResourceName};
+use std::str::FromStr;
+
+#[test]
+fn test_cloudwatch_alarm() {
+    let expected = "arn:aws:cloudwatch:us-west-2:123456789012:alarm:Production:LB:High4xx";
+    let arn = cloudwatch::alarm(
+        Partition::Aws,
+        Region::UsWest2,
+        AccountId::new_unchecked("123456789012"),
+        ResourceIdentifier::new_unchecked("Production:LB:High4xx"),
+    );
+    assert_eq!(arn.to_string(), expected);
+    assert_eq!(arn, ResourceName::from_str(expected).unwrap());
+}
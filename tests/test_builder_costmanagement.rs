@@ -0,0 +1,56 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::costmanagement;
+use aws_arn::{AccountId, Identifier, IdentifierLike, Partition};
+
+#[test]
+fn test_savings_plan() {
+    let arn = costmanagement::savings_plan(
+        Partition::Aws,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("abcd1234-ab12-cd34-ef56-abcdef123456"),
+    );
+    assert_eq!(arn.region, None);
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:savingsplans::123456789012:savingsplan/abcd1234-ab12-cd34-ef56-abcdef123456"
+    );
+}
+
+#[test]
+fn test_budget() {
+    let arn = costmanagement::budget(
+        Partition::Aws,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("MonthlyBudget"),
+    );
+    assert_eq!(arn.region, None);
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:budgets::123456789012:budget/MonthlyBudget"
+    );
+}
+
+#[test]
+fn test_cost_explorer() {
+    let arn = costmanagement::cost_category(
+        Partition::Aws,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("a1b2c3d4"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:ce::123456789012:costcategory/a1b2c3d4"
+    );
+
+    let arn = costmanagement::anomaly_monitor(
+        Partition::Aws,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("a1b2c3d4"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:ce::123456789012:anomalymonitor/a1b2c3d4"
+    );
+    assert_eq!(arn.validate(), Ok(()));
+}
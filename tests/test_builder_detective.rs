@@ -0,0 +1,18 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::detective;
+use aws_arn::{AccountId, Identifier, IdentifierLike, Partition, Region};
+
+#[test]
+fn test_detective_graph() {
+    let arn = detective::graph(
+        Partition::Aws,
+        Region::UsEast1,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("027c7c4610ea4aacaf0b883093cab899"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:detective:us-east-1:123456789012:graph:027c7c4610ea4aacaf0b883093cab899"
+    );
+}
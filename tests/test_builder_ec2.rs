@@ -0,0 +1,58 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::ec2;
+use aws_arn::{AccountId, Identifier, IdentifierLike, Partition, Region};
+
+#[test]
+fn test_ec2_image() {
+    let arn = ec2::image(
+        Partition::Aws,
+        Region::UsEast1,
+        Identifier::new_unchecked("ami-0abcdef1234567890"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:ec2:us-east-1::image/ami-0abcdef1234567890"
+    );
+}
+
+#[test]
+fn test_ec2_snapshot() {
+    let arn = ec2::snapshot(
+        Partition::Aws,
+        Region::UsEast1,
+        Identifier::new_unchecked("snap-1234567890abcdef0"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:ec2:us-east-1::snapshot/snap-1234567890abcdef0"
+    );
+}
+
+#[test]
+fn test_ec2_launch_template() {
+    let arn = ec2::launch_template(
+        Partition::Aws,
+        Region::UsEast1,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("lt-0abcd290751193123"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:ec2:us-east-1:123456789012:launch-template/lt-0abcd290751193123"
+    );
+}
+
+#[test]
+fn test_ec2_key_pair() {
+    let arn = ec2::key_pair(
+        Partition::Aws,
+        Region::UsEast1,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("my-key-pair"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:ec2:us-east-1:123456789012:key-pair/my-key-pair"
+    );
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::ecr;
+use aws_arn::{AccountId, IdentifierLike, Partition, Region, ResourceIdentifier, ResourceName};
+use std::str::FromStr;
+
+fn account() -> AccountId {
+    AccountId::new_unchecked("123456789012")
+}
+
+#[test]
+fn test_ecr_repository() {
+    let arn = ecr::repository(
+        Partition::Aws,
+        Region::UsEast1,
+        account(),
+        ResourceIdentifier::new_unchecked("my-repo"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:ecr:us-east-1:123456789012:repository/my-repo"
+    );
+}
+
+#[test]
+fn test_ecr_namespaced_repository() {
+    let arn = ecr::repository(
+        Partition::Aws,
+        Region::EuWest1,
+        account(),
+        ResourceIdentifier::new_unchecked("team/service"),
+    );
+    let expected = "arn:aws:ecr:eu-west-1:123456789012:repository/team/service";
+    assert_eq!(arn.to_string(), expected);
+    assert_eq!(ResourceName::from_str(expected).unwrap(), arn);
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::ecs;
+use aws_arn::{AccountId, Identifier, IdentifierLike, Partition, Region, ResourceName};
+use std::str::FromStr;
+
+fn account() -> AccountId {
+    AccountId::new_unchecked("123456789012")
+}
+
+#[test]
+fn test_ecs_cluster() {
+    let arn = ecs::cluster(
+        Partition::Aws,
+        Region::UsEast1,
+        account(),
+        Identifier::new_unchecked("my-cluster"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:ecs:us-east-1:123456789012:cluster/my-cluster"
+    );
+}
+
+#[test]
+fn test_ecs_service() {
+    let arn = ecs::service(
+        Partition::Aws,
+        Region::UsEast1,
+        account(),
+        Identifier::new_unchecked("my-cluster"),
+        Identifier::new_unchecked("my-service"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:ecs:us-east-1:123456789012:service/my-cluster/my-service"
+    );
+}
+
+#[test]
+fn test_ecs_task() {
+    let expected =
+        "arn:aws:ecs:us-east-1:123456789012:task/my-cluster/a1b2c3d4e5f67890a1b2c3d4e5f67890";
+    let arn = ecs::task(
+        Partition::Aws,
+        Region::UsEast1,
+        account(),
+        Identifier::new_unchecked("my-cluster"),
+        Identifier::new_unchecked("a1b2c3d4e5f67890a1b2c3d4e5f67890"),
+    );
+    assert_eq!(arn.to_string(), expected);
+    assert_eq!(arn, ResourceName::from_str(expected).unwrap());
+}
+
+#[test]
+fn test_ecs_task_definition() {
+    let arn = ecs::task_definition(
+        Partition::Aws,
+        Region::UsEast1,
+        account(),
+        Identifier::new_unchecked("my-family"),
+        3,
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:ecs:us-east-1:123456789012:task-definition/my-family:3"
+    );
+}
@@ -0,0 +1,18 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::guardduty;
+use aws_arn::{AccountId, Identifier, IdentifierLike, Partition, Region};
+
+#[test]
+fn test_guardduty_detector() {
+    let arn = guardduty::detector(
+        Partition::Aws,
+        Region::UsEast1,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("12abc34d567e8fa901bc2d34e56789f0"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:guardduty:us-east-1:123456789012:detector/12abc34d567e8fa901bc2d34e56789f0"
+    );
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::iam;
+use aws_arn::{AccountId, Identifier, IdentifierLike, Partition, ResourceIdentifier};
+
+fn account() -> AccountId {
+    AccountId::new_unchecked("123456789012")
+}
+
+#[test]
+fn test_iam_instance_profile() {
+    let arn = iam::instance_profile(
+        Partition::Aws,
+        account(),
+        Identifier::new_unchecked("MyProfile"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:iam::123456789012:instance-profile/MyProfile"
+    );
+}
+
+#[test]
+fn test_iam_saml_provider() {
+    let arn = iam::saml_provider(Partition::Aws, account(), Identifier::new_unchecked("ADFS"));
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:iam::123456789012:saml-provider/ADFS"
+    );
+}
+
+#[test]
+fn test_iam_oidc_provider() {
+    let arn = iam::oidc_provider(
+        Partition::Aws,
+        account(),
+        ResourceIdentifier::new_unchecked("oidc.eks.us-east-1.amazonaws.com/id/EXAMPLE"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:iam::123456789012:oidc-provider/oidc.eks.us-east-1.amazonaws.com/id/EXAMPLE"
+    );
+}
+
+#[test]
+fn test_iam_server_certificate() {
+    let arn = iam::server_certificate(
+        Partition::AwsChina,
+        account(),
+        Identifier::new_unchecked("ProdServerCert"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws-cn:iam::123456789012:server-certificate/ProdServerCert"
+    );
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::lex;
+use aws_arn::{AccountId, Identifier, IdentifierLike, Partition, Region, ResourceName, Service};
+use std::str::FromStr;
+
+#[test]
+fn test_lex_bot() {
+    let arn = lex::bot(
+        Partition::Aws,
+        Region::UsEast1,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("ABCDEFGHIJ"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:lex:us-east-1:123456789012:bot/ABCDEFGHIJ"
+    );
+}
+
+#[test]
+fn test_lex_bot_alias() {
+    let arn = lex::bot_alias(
+        Partition::Aws,
+        Region::UsEast1,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("ABCDEFGHIJ"),
+        Identifier::new_unchecked("TSTALIASID"),
+    );
+    let expected = "arn:aws:lex:us-east-1:123456789012:bot-alias/ABCDEFGHIJ/TSTALIASID";
+    assert_eq!(arn.to_string(), expected);
+    assert_eq!(
+        ResourceName::from_str(expected).unwrap().service,
+        Service::Lex
+    );
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::logs;
+use aws_arn::{AccountId, IdentifierLike, Partition, Region, ResourceIdentifier};
+
+#[test]
+fn test_logs_log_group() {
+    let arn = logs::log_group(
+        Partition::Aws,
+        Region::UsEast1,
+        AccountId::new_unchecked("123456789012"),
+        ResourceIdentifier::new_unchecked("/aws/lambda/my-function"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/my-function:*"
+    );
+}
+
+#[test]
+fn test_logs_log_stream() {
+    let arn = logs::log_stream(
+        Partition::Aws,
+        Region::UsEast1,
+        AccountId::new_unchecked("123456789012"),
+        ResourceIdentifier::new_unchecked("my-group"),
+        ResourceIdentifier::new_unchecked("2024/01/01/[$LATEST]abcdef"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:logs:us-east-1:123456789012:log-group:my-group:log-stream:2024/01/01/[$LATEST]abcdef"
+    );
+}
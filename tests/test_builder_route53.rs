@@ -0,0 +1,22 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::route53;
+use aws_arn::{Identifier, IdentifierLike, Partition};
+
+#[test]
+fn test_route53_hosted_zone() {
+    let arn = route53::hosted_zone(Partition::Aws, Identifier::new_unchecked("Z123"));
+    assert_eq!(arn.to_string(), "arn:aws:route53:::hostedzone/Z123");
+    assert_eq!(arn.region, None);
+    assert_eq!(arn.account_id, None);
+    assert_eq!(arn.validate(), Ok(()));
+}
+
+#[test]
+fn test_route53_health_check() {
+    let arn = route53::health_check(Partition::AwsUsGov, Identifier::new_unchecked("abc"));
+    assert_eq!(arn.to_string(), "arn:aws-us-gov:route53:::healthcheck/abc");
+    assert_eq!(arn.region, None);
+    assert_eq!(arn.account_id, None);
+    assert_eq!(arn.validate(), Ok(()));
+}
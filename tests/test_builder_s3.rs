@@ -0,0 +1,148 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::s3;
+use aws_arn::{AccountId, ArnError, Identifier, IdentifierLike, Partition, Region, ResourceName};
+use std::str::FromStr;
+
+#[test]
+fn test_s3_bucket() {
+    let arn = s3::bucket(Identifier::new_unchecked("my-bucket"));
+    assert_eq!(arn.to_string(), "arn:aws:s3:::my-bucket");
+
+    let arn = s3::bucket_in(Partition::AwsChina, Identifier::new_unchecked("my-bucket"));
+    assert_eq!(arn.to_string(), "arn:aws-cn:s3:::my-bucket");
+}
+
+#[test]
+fn test_s3_object() {
+    let arn = s3::object(
+        Identifier::new_unchecked("my-bucket"),
+        Identifier::new_unchecked("index.html"),
+    );
+    assert_eq!(arn.to_string(), "arn:aws:s3:::my-bucket/index.html");
+
+    let arn = s3::object_in(
+        Partition::AwsUsGov,
+        Identifier::new_unchecked("my-bucket"),
+        Identifier::new_unchecked("index.html"),
+    );
+    assert_eq!(arn.to_string(), "arn:aws-us-gov:s3:::my-bucket/index.html");
+}
+
+#[test]
+fn test_s3_object_from_bucket() {
+    let bucket = s3::bucket_in(Partition::AwsChina, Identifier::new_unchecked("my-bucket"));
+    let arn = s3::object_from(&bucket, Identifier::new_unchecked("index.html")).unwrap();
+    assert_eq!(arn.to_string(), "arn:aws-cn:s3:::my-bucket/index.html");
+}
+
+#[test]
+fn test_s3_object_from_non_s3() {
+    let not_bucket = ResourceName::from_str("arn:aws:iam::123456789012:user/Bob").unwrap();
+    assert_eq!(
+        s3::object_from(&not_bucket, Identifier::new_unchecked("index.html")),
+        Err(ArnError::UnexpectedService {
+            expected: "s3".to_string(),
+            actual: "iam".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_s3_job() {
+    let arn = s3::job(
+        Region::UsEast1,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("job/23476"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:s3:us-east-1:123456789012:job/23476"
+    );
+
+    let arn = s3::job_in(
+        Partition::AwsChina,
+        Region::UsEast1,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("job/23476"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws-cn:s3:us-east-1:123456789012:job/23476"
+    );
+}
+
+#[test]
+fn test_s3_access_point() {
+    let arn = s3::access_point(
+        Partition::Aws,
+        Region::UsWest2,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("my-access-point"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:s3:us-west-2:123456789012:accesspoint/my-access-point"
+    );
+}
+
+#[test]
+fn test_s3_outpost_bucket() {
+    let arn = s3::outpost_bucket(
+        Partition::Aws,
+        Region::UsWest2,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("op-01ac5d28a6a232904"),
+        Identifier::new_unchecked("my-bucket"),
+    );
+    let expected =
+        "arn:aws:s3outposts:us-west-2:123456789012:outpost/op-01ac5d28a6a232904/bucket/my-bucket";
+    assert_eq!(arn.to_string(), expected);
+    assert_eq!(ResourceName::from_str(expected).unwrap(), arn);
+}
+
+fn arn(s: &str) -> ResourceName {
+    ResourceName::from_str(s).unwrap()
+}
+
+#[test]
+fn test_s3_bucket_matches_prefix() {
+    let object = arn("arn:aws:s3:::my-bucket/logs/2024/01.gz");
+    assert!(s3::bucket_matches(
+        &arn("arn:aws:s3:::my-bucket/*"),
+        &object
+    ));
+    assert!(s3::bucket_matches(
+        &arn("arn:aws:s3:::my-bucket/logs/*"),
+        &object
+    ));
+    assert!(!s3::bucket_matches(
+        &arn("arn:aws:s3:::my-bucket/images/*"),
+        &object
+    ));
+}
+
+#[test]
+fn test_s3_bucket_matches_exact() {
+    let object = arn("arn:aws:s3:::my-bucket/index.html");
+    assert!(s3::bucket_matches(&object, &object));
+    let bucket = arn("arn:aws:s3:::my-bucket");
+    assert!(s3::bucket_matches(&bucket, &bucket));
+    assert!(!s3::bucket_matches(&bucket, &object));
+}
+
+#[test]
+fn test_s3_bucket_matches_other_bucket() {
+    assert!(!s3::bucket_matches(
+        &arn("arn:aws:s3:::my-bucket/*"),
+        &arn("arn:aws:s3:::other-bucket/logs/01.gz")
+    ));
+}
+
+#[test]
+fn test_s3_bucket_matches_bucket_wildcard() {
+    let object = arn("arn:aws:s3:::my-bucket/key");
+    assert!(s3::bucket_matches(&arn("arn:aws:s3:::*"), &object));
+    assert!(s3::bucket_matches(&arn("arn:aws:s3:::my-*"), &object));
+    assert!(!s3::bucket_matches(&arn("arn:aws:s3:::other-*"), &object));
+}
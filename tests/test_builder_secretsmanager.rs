@@ -0,0 +1,33 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::secretsmanager;
+use aws_arn::{AccountId, Identifier, IdentifierLike, Partition, Region, ResourceIdentifier};
+
+#[test]
+fn test_secretsmanager_secret() {
+    let arn = secretsmanager::secret(
+        Partition::Aws,
+        Region::UsEast1,
+        AccountId::new_unchecked("123456789012"),
+        ResourceIdentifier::new_unchecked("prod/db/password"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:secretsmanager:us-east-1:123456789012:secret:prod/db/password"
+    );
+}
+
+#[test]
+fn test_secretsmanager_secret_with_suffix() {
+    let arn = secretsmanager::secret_with_suffix(
+        Partition::Aws,
+        Region::UsEast1,
+        AccountId::new_unchecked("123456789012"),
+        ResourceIdentifier::new_unchecked("MySecret"),
+        Identifier::new_unchecked("a1b2c3"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:secretsmanager:us-east-1:123456789012:secret:MySecret-a1b2c3"
+    );
+}
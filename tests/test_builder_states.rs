@@ -0,0 +1,33 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::states;
+use aws_arn::{AccountId, Identifier, IdentifierLike, Partition, Region, ResourceName};
+use std::str::FromStr;
+
+#[test]
+fn test_states_state_machine() {
+    let expected = "arn:aws:states:us-east-1:123456789012:stateMachine:HelloWorld";
+    let arn = states::state_machine(
+        Partition::Aws,
+        Region::UsEast1,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("HelloWorld"),
+    );
+    assert_eq!(arn.to_string(), expected);
+    assert_eq!(arn, ResourceName::from_str(expected).unwrap());
+}
+
+#[test]
+fn test_states_execution() {
+    let arn = states::execution(
+        Partition::Aws,
+        Region::UsEast1,
+        AccountId::new_unchecked("123456789012"),
+        Identifier::new_unchecked("HelloWorld"),
+        Identifier::new_unchecked("my-execution"),
+    );
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:states:us-east-1:123456789012:execution:HelloWorld:my-execution"
+    );
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "compact")]
+use aws_arn::{AccountId, Identifier, IdentifierLike, ResourceIdentifier, ResourceName};
+use std::str::FromStr;
+
+#[test]
+fn test_compact_identifiers_deref_to_str() {
+    let id = Identifier::from_str("us-east-1").unwrap();
+    let account = AccountId::from_str("123456789012").unwrap();
+    let resource = ResourceIdentifier::from_str("user/Bob").unwrap();
+    let as_str: &str = &id;
+    assert_eq!(as_str, "us-east-1");
+    assert_eq!(&*account, "123456789012");
+    assert_eq!(
+        resource.path_segments().collect::<Vec<_>>(),
+        vec!["user", "Bob"]
+    );
+    assert_eq!(String::from(resource), "user/Bob");
+}
+
+#[test]
+fn test_compact_arn_round_trip() {
+    let input = "arn:aws:lambda:us-east-2:123456789012:function:my-function";
+    let arn = ResourceName::from_str(input).unwrap();
+    assert_eq!(arn.to_string(), input);
+    assert!(ResourceIdentifier::is_valid(&arn.resource));
+}
@@ -0,0 +1,166 @@
+use aws_arn::{ArnError, ArnErrorCode, ResourceName};
+use rstest::rstest;
+use std::error::Error;
+use std::str::FromStr;
+
+#[rstest]
+#[case(ArnError::TooShort(3), ArnErrorCode::TooShort, "too_short")]
+#[case(ArnError::TooLong(4096), ArnErrorCode::TooLong, "too_long")]
+#[case(
+    ArnError::TooFewComponents(3),
+    ArnErrorCode::TooFewComponents,
+    "too_few_components"
+)]
+#[case(
+    ArnError::InvalidIdentifier("a b".to_string()),
+    ArnErrorCode::InvalidIdentifier,
+    "invalid_identifier"
+)]
+#[case(ArnError::MissingPrefix, ArnErrorCode::MissingPrefix, "missing_prefix")]
+#[case(
+    ArnError::MissingPartition,
+    ArnErrorCode::MissingPartition,
+    "missing_partition"
+)]
+#[case(
+    ArnError::InvalidPartition("aws-mars".to_string()),
+    ArnErrorCode::InvalidPartition,
+    "invalid_partition"
+)]
+#[case(
+    ArnError::MissingService,
+    ArnErrorCode::MissingService,
+    "missing_service"
+)]
+#[case(
+    ArnError::InvalidService("nope".to_string()),
+    ArnErrorCode::InvalidService,
+    "invalid_service"
+)]
+#[case(
+    ArnError::UnexpectedService { expected: "s3".to_string(), actual: "sqs".to_string() },
+    ArnErrorCode::UnexpectedService,
+    "unexpected_service"
+)]
+#[case(ArnError::MissingRegion, ArnErrorCode::MissingRegion, "missing_region")]
+#[case(
+    ArnError::InvalidRegion("us-nowhere-1".to_string()),
+    ArnErrorCode::InvalidRegion,
+    "invalid_region"
+)]
+#[case(
+    ArnError::PartitionRegionMismatch { partition: "aws".to_string(), region: "cn-north-1".to_string() },
+    ArnErrorCode::PartitionRegionMismatch,
+    "partition_region_mismatch"
+)]
+#[case(
+    ArnError::RegionNotAllowed("iam".to_string()),
+    ArnErrorCode::RegionNotAllowed,
+    "region_not_allowed"
+)]
+#[case(
+    ArnError::RegionWildcardNotAllowed("bucket".to_string()),
+    ArnErrorCode::RegionWildcardNotAllowed,
+    "region_wildcard_not_allowed"
+)]
+#[case(
+    ArnError::MissingAccountId,
+    ArnErrorCode::MissingAccountId,
+    "missing_account_id"
+)]
+#[case(
+    ArnError::InvalidAccountId("1234".to_string()),
+    ArnErrorCode::InvalidAccountId,
+    "invalid_account_id"
+)]
+#[case(
+    ArnError::MissingAccountPlaceholder,
+    ArnErrorCode::MissingAccountPlaceholder,
+    "missing_account_placeholder"
+)]
+#[case(
+    ArnError::AccountIdWildcardNotAllowed("s3".to_string()),
+    ArnErrorCode::AccountIdWildcardNotAllowed,
+    "account_id_wildcard_not_allowed"
+)]
+#[case(
+    ArnError::MissingResource,
+    ArnErrorCode::MissingResource,
+    "missing_resource"
+)]
+#[case(
+    ArnError::InvalidResource("".to_string()),
+    ArnErrorCode::InvalidResource,
+    "invalid_resource"
+)]
+#[case(
+    ArnError::InvalidResourceCharacter('\t'),
+    ArnErrorCode::InvalidResourceCharacter,
+    "invalid_resource_character"
+)]
+#[case(
+    ArnError::ResourceWildcardNotAllowed("bucket".to_string()),
+    ArnErrorCode::ResourceWildcardNotAllowed,
+    "resource_wildcard_not_allowed"
+)]
+#[case(
+    ArnError::InvalidPolicyResource("42".to_string()),
+    ArnErrorCode::InvalidPolicyResource,
+    "invalid_policy_resource"
+)]
+#[case(
+    ArnError::SeparatorCountMismatch {
+        expected: 2,
+        actual: 1
+    },
+    ArnErrorCode::SeparatorCountMismatch,
+    "separator_count_mismatch"
+)]
+#[allow(deprecated)]
+fn test_error_code(#[case] error: ArnError, #[case] code: ArnErrorCode, #[case] name: &str) {
+    assert_eq!(error.code(), code);
+    assert_eq!(code.to_string(), name);
+}
+
+#[test]
+fn test_component_error_code_is_source_code() {
+    let error = ArnError::Component {
+        index: 3,
+        source: Box::new(ArnError::InvalidRegion("us-nowhere-1".to_string())),
+    };
+    assert_eq!(error.code(), ArnErrorCode::InvalidRegion);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_error_code_serialize() {
+    assert_eq!(
+        serde_json::to_string(&ArnErrorCode::InvalidAccountId).unwrap(),
+        r#""invalid_account_id""#
+    );
+}
+
+#[test]
+fn test_component_error_source_chain() {
+    let error = ResourceName::from_str("arn:aws:sqs:us-nowhere-1:123456789012:queue").unwrap_err();
+    let source = error
+        .source()
+        .expect("component error should have a source");
+    assert_eq!(source.to_string(), "us-nowhere-1 is not a valid region");
+    assert_eq!(
+        source.downcast_ref::<ArnError>(),
+        Some(&ArnError::InvalidRegion("us-nowhere-1".to_string()))
+    );
+    assert!(source.source().is_none());
+
+    let chain: Vec<String> = std::iter::successors(Some(&error as &dyn Error), |&e| e.source())
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        chain,
+        vec![
+            "component 3 (region) is invalid".to_string(),
+            "us-nowhere-1 is not a valid region".to_string(),
+        ]
+    );
+}
@@ -31,3 +31,20 @@ fn test_examples_from_file() {
         }
     }
 }
+
+#[test]
+fn test_examples_round_trip() {
+    for arn_str in EXAMPLES
+        .lines()
+        .chain(EXAMPLES_WITH_SERVICES.lines())
+        .filter(|line| !line.starts_with('#'))
+    {
+        let parsed = ResourceName::from_str(arn_str).unwrap();
+        assert_eq!(
+            ResourceName::from_str(&parsed.to_string()),
+            Ok(parsed),
+            "{} did not round-trip",
+            arn_str
+        );
+    }
+}
@@ -1,5 +1,6 @@
-use aws_arn::{Identifier, IdentifierLike};
+use aws_arn::{ArnError, Identifier, IdentifierLike, MAX_IDENTIFIER_LENGTH};
 use proptest::prelude::*;
+use std::convert::TryFrom;
 use std::str::FromStr;
 
 // ------------------------------------------------------------------------------------------------
@@ -62,6 +63,43 @@ fn test_identifier_is_not_valid() {
     assert!(!Identifier::is_valid("/"));
 }
 
+#[test]
+fn test_identifier_new_validates() {
+    assert_eq!(Identifier::new("a").unwrap().to_string(), "a");
+    assert_eq!(
+        Identifier::new("a/b"),
+        Err(ArnError::InvalidIdentifier("a/b".to_string()))
+    );
+}
+
+#[test]
+fn test_identifier_max_length() {
+    let longest = "a".repeat(MAX_IDENTIFIER_LENGTH);
+    assert!(Identifier::is_valid(&longest));
+    assert!(Identifier::new(&longest).is_ok());
+
+    let too_long = "a".repeat(MAX_IDENTIFIER_LENGTH + 1);
+    assert!(!Identifier::is_valid(&too_long));
+    assert_eq!(
+        Identifier::new(&too_long),
+        Err(ArnError::InvalidIdentifier(too_long.clone()))
+    );
+    assert_eq!(
+        Identifier::try_from(too_long.clone()),
+        Err(ArnError::InvalidIdentifier(too_long))
+    );
+}
+
+#[test]
+fn test_identifier_try_from_string() {
+    let owned = Identifier::try_from("us-east-1".to_string()).unwrap();
+    assert_eq!(owned, Identifier::from_str("us-east-1").unwrap());
+    assert_eq!(
+        Identifier::try_from("us east".to_string()),
+        Err(ArnError::InvalidIdentifier("us east".to_string()))
+    );
+}
+
 // ------------------------------------------------------------------------------------------------
 // Automated Property Tests
 // ------------------------------------------------------------------------------------------------
@@ -0,0 +1,26 @@
+use aws_arn::{arn, ResourceName, Service};
+use std::str::FromStr;
+
+#[test]
+fn test_arn_macro_valid() {
+    let bucket = arn!("arn:aws:s3:::my-bucket");
+    assert_eq!(bucket.service, Service::S3);
+    assert_eq!(
+        *bucket,
+        ResourceName::from_str("arn:aws:s3:::my-bucket").unwrap()
+    );
+}
+
+#[test]
+fn test_arn_macro_parsed_once() {
+    fn queue() -> &'static ResourceName {
+        arn!("arn:aws:sqs:us-east-1:123456789012:my-queue")
+    }
+    assert!(std::ptr::eq(queue(), queue()));
+}
+
+#[test]
+#[should_panic(expected = "invalid ARN literal \"arn:aws:s3\"")]
+fn test_arn_macro_invalid() {
+    let _ = arn!("arn:aws:s3");
+}
@@ -0,0 +1,36 @@
+use aws_arn::{Partition, Region, Service};
+
+// These enums are `#[non_exhaustive]`, so a downstream match must include a wildcard arm.
+
+fn describe_partition(partition: &Partition) -> &'static str {
+    match partition {
+        Partition::Aws => "commercial",
+        Partition::AwsChina => "china",
+        _ => "other",
+    }
+}
+
+fn describe_region(region: &Region) -> &'static str {
+    match region {
+        Region::UsEast1 | Region::UsEast2 => "us-east",
+        _ => "other",
+    }
+}
+
+fn describe_service(service: &Service) -> &'static str {
+    match service {
+        Service::S3 => "storage",
+        Service::Lambda => "compute",
+        _ => "other",
+    }
+}
+
+#[test]
+fn test_downstream_match_with_wildcard() {
+    assert_eq!(describe_partition(&Partition::Aws), "commercial");
+    assert_eq!(describe_partition(&Partition::AwsUsGov), "other");
+    assert_eq!(describe_region(&Region::UsEast2), "us-east");
+    assert_eq!(describe_region(&Region::EuWest1), "other");
+    assert_eq!(describe_service(&Service::Lambda), "compute");
+    assert_eq!(describe_service(&Service::Ec2), "other");
+}
@@ -0,0 +1,54 @@
+use aws_arn::{Partition, Region};
+use rstest::rstest;
+use std::str::FromStr;
+use strum::VariantArray;
+
+#[rstest]
+#[case::lower("aws", Partition::Aws)]
+#[case::upper("AWS", Partition::Aws)]
+#[case::china("AWS-CN", Partition::AwsChina)]
+#[case::gov("Aws-Us-Gov", Partition::AwsUsGov)]
+fn test_partition_from_str_ignore_case(#[case] input: &str, #[case] expected: Partition) {
+    let partition = Partition::from_str(input).unwrap();
+    assert_eq!(partition, expected);
+    assert_eq!(partition.to_string(), input.to_ascii_lowercase());
+}
+
+#[test]
+fn test_partition_from_str_invalid() {
+    assert!(Partition::from_str("aws-moon").is_err());
+}
+
+#[test]
+fn test_partition_regions_consistent() {
+    for region in Region::VARIANTS {
+        let containing: Vec<&Partition> = Partition::VARIANTS
+            .iter()
+            .filter(|partition| partition.regions().contains(region))
+            .collect();
+        assert_eq!(
+            containing,
+            vec![&Partition::from_region(region)],
+            "{}",
+            region
+        );
+    }
+}
+
+#[test]
+fn test_partition_from_region() {
+    assert_eq!(Partition::from_region(&Region::UsEast1), Partition::Aws);
+    assert_eq!(
+        Partition::from_region(&Region::CnNorth1),
+        Partition::AwsChina
+    );
+    assert_eq!(
+        Partition::from_region(&Region::UsGovWest1),
+        Partition::AwsUsGov
+    );
+    assert_eq!(
+        Partition::from_region(&Region::UsGovGlobal),
+        Partition::AwsUsGov
+    );
+    assert!(Partition::AwsUsGov.regions().contains(&Region::UsGovEast1));
+}
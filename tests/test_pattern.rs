@@ -0,0 +1,228 @@
+use aws_arn::{find_by_prefix, ArnError, ArnPattern, ResourceName, ResourceNamePattern};
+use std::str::FromStr;
+
+#[test]
+fn test_concrete_pattern_equals_resource_name() {
+    let arn = ResourceName::from_str("arn:aws:s3:us-east-1:123456789012:job/23476").unwrap();
+    let pattern =
+        ResourceNamePattern::from_str("arn:aws:s3:us-east-1:123456789012:job/23476").unwrap();
+    assert!(pattern.is_concrete());
+    assert!(pattern == arn);
+    assert!(arn == pattern);
+}
+
+#[test]
+fn test_wildcard_pattern_not_equal_resource_name() {
+    let arn = ResourceName::from_str("arn:aws:s3:::my-bucket/*").unwrap();
+    let pattern = ResourceNamePattern::from_str("arn:aws:s3:::my-bucket/*").unwrap();
+    assert!(!pattern.is_concrete());
+    assert!(pattern != arn);
+
+    let pattern = ResourceNamePattern::from_str("arn:aws:logs:us-east-1:*:log-group:x").unwrap();
+    assert!(!pattern.is_concrete());
+}
+
+fn sorted_arns() -> Vec<ResourceName> {
+    let mut arns: Vec<ResourceName> = [
+        "arn:aws:s3:::my-bucket/a",
+        "arn:aws:lambda:us-east-1:123456789012:function:f",
+        "arn:aws:s3:::my-bucket/b",
+        "arn:aws:lambda:eu-west-1:123456789012:function:f",
+        "arn:aws:s3:::other-bucket/a",
+        "arn:aws:lambda:us-east-1:210987654321:function:g",
+        "arn:aws:lambda:us-east-1:123456789012:function:g",
+        "arn:aws-cn:lambda:us-east-1:123456789012:function:f",
+    ]
+    .iter()
+    .map(|s| ResourceName::from_str(s).unwrap())
+    .collect();
+    arns.sort();
+    arns
+}
+
+fn find(arns: &[ResourceName], prefix: &str) -> Vec<String> {
+    find_by_prefix(arns, &ResourceNamePattern::from_str(prefix).unwrap())
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[test]
+fn test_find_by_prefix_service_and_region() {
+    let arns = sorted_arns();
+    assert_eq!(
+        find(&arns, "arn:aws:lambda:us-east-1:*:*"),
+        vec![
+            "arn:aws:lambda:us-east-1:123456789012:function:f",
+            "arn:aws:lambda:us-east-1:123456789012:function:g",
+            "arn:aws:lambda:us-east-1:210987654321:function:g",
+        ]
+    );
+    assert_eq!(
+        find(&arns, "arn:aws:lambda:us-east-1:123456789012:function:*"),
+        vec![
+            "arn:aws:lambda:us-east-1:123456789012:function:f",
+            "arn:aws:lambda:us-east-1:123456789012:function:g",
+        ]
+    );
+}
+
+#[test]
+fn test_find_by_prefix_resource() {
+    let arns = sorted_arns();
+    assert_eq!(
+        find(&arns, "arn:aws:s3:::my-bucket/*"),
+        vec!["arn:aws:s3:::my-bucket/a", "arn:aws:s3:::my-bucket/b"]
+    );
+    assert_eq!(
+        find(&arns, "arn:aws:s3:::my-bucket/b"),
+        vec!["arn:aws:s3:::my-bucket/b"]
+    );
+    assert!(find(&arns, "arn:aws:s3:::no-bucket/*").is_empty());
+}
+
+#[test]
+fn test_pattern_matches_bucket_prefix() {
+    let pattern = ArnPattern::from_str("arn:aws:s3:::my-bucket/*").unwrap();
+    for arn in &[
+        "arn:aws:s3:::my-bucket/a",
+        "arn:aws:s3:::my-bucket/logs/2024/01/01.gz",
+        "arn:aws:s3:::my-bucket/",
+    ] {
+        assert!(
+            pattern.matches(&ResourceName::from_str(arn).unwrap()),
+            "{}",
+            arn
+        );
+    }
+    for arn in &[
+        "arn:aws:s3:::my-bucket",
+        "arn:aws:s3:::other-bucket/a",
+        "arn:aws-cn:s3:::my-bucket/a",
+        "arn:aws:s3outposts:::my-bucket/a",
+    ] {
+        assert!(
+            !pattern.matches(&ResourceName::from_str(arn).unwrap()),
+            "{}",
+            arn
+        );
+    }
+}
+
+#[test]
+fn test_pattern_matches_single_character_and_account() {
+    let pattern = ArnPattern::from_str("arn:aws:sqs:us-east-1:*:queue-?").unwrap();
+    assert!(pattern
+        .matches(&ResourceName::from_str("arn:aws:sqs:us-east-1:123456789012:queue-1").unwrap()));
+    assert!(!pattern
+        .matches(&ResourceName::from_str("arn:aws:sqs:us-east-1:123456789012:queue-10").unwrap()));
+    assert!(!pattern
+        .matches(&ResourceName::from_str("arn:aws:sqs:us-west-2:123456789012:queue-1").unwrap()));
+    assert!(!pattern
+        .matches(&ResourceName::from_str("arn:aws:sns:us-east-1:123456789012:queue-1").unwrap()));
+}
+
+#[test]
+fn test_pattern_matches_multiple_wildcards() {
+    let pattern =
+        ArnPattern::from_str("arn:aws:logs:us-east-1:123456789012:log-group:*:log-stream:*")
+            .unwrap();
+    assert!(pattern.matches(
+        &ResourceName::from_str(
+            "arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/f:log-stream:2024/01/01"
+        )
+        .unwrap()
+    ));
+    assert!(!pattern.matches(
+        &ResourceName::from_str("arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/f:*")
+            .unwrap()
+    ));
+}
+
+#[test]
+fn test_pattern_matches_region_wildcard() {
+    let pattern = ArnPattern::from_str("arn:aws:sqs:us-*-?:123456789012:my-queue").unwrap();
+    assert!(!pattern.is_concrete());
+    for arn in &[
+        "arn:aws:sqs:us-east-1:123456789012:my-queue",
+        "arn:aws:sqs:us-west-2:123456789012:my-queue",
+    ] {
+        assert!(
+            pattern.matches(&ResourceName::from_str(arn).unwrap()),
+            "{}",
+            arn
+        );
+    }
+    for arn in &[
+        "arn:aws:sqs:eu-west-1:123456789012:my-queue",
+        "arn:aws:sqs::123456789012:my-queue",
+    ] {
+        assert!(
+            !pattern.matches(&ResourceName::from_str(arn).unwrap()),
+            "{}",
+            arn
+        );
+    }
+}
+
+#[test]
+fn test_pattern_wildcard_matches_absent_component() {
+    let pattern = ArnPattern::from_str("arn:aws:s3:*:*:my-bucket").unwrap();
+    assert!(pattern.matches(&ResourceName::from_str("arn:aws:s3:::my-bucket").unwrap()));
+
+    let pattern = ArnPattern::from_str("arn:aws:s3:::my-bucket").unwrap();
+    assert!(pattern.is_concrete());
+    assert!(!pattern.matches(&ResourceName::from_str("arn:aws:s3:us-east-1::my-bucket").unwrap()));
+}
+
+#[test]
+fn test_pattern_parse_and_display() {
+    let pattern = ArnPattern::from_str("arn:aws:sqs:US-EAST-1:*:queue-?").unwrap();
+    assert_eq!(pattern.to_string(), "arn:aws:sqs:us-east-1:*:queue-?");
+    assert!(pattern
+        .matches(&ResourceName::from_str("arn:aws:sqs:us-east-1:123456789012:queue-1").unwrap()));
+
+    let arn = ResourceName::from_str("arn:aws:s3:::my-bucket/*").unwrap();
+    assert_eq!(ArnPattern::from(&arn).to_string(), arn.to_string());
+
+    let error = ArnPattern::from_str("arn:aws:sqs:us_*:*:queue").unwrap_err();
+    assert_eq!(error.component_index(), Some(3));
+    assert_eq!(
+        ArnPattern::from_str("arn:aws:sqs:us-*:*:"),
+        Err(ArnError::MissingResource)
+    );
+}
+
+#[test]
+fn test_pattern_region_wildcard_ignores_case() {
+    let arn = ResourceName::from_str("arn:aws:s3:us-east-1:123456789012:job/23476").unwrap();
+    let pattern = ArnPattern::from_str("arn:aws:s3:US-*:123456789012:job/*").unwrap();
+    assert!(pattern.matches(&arn));
+    assert_eq!(pattern.to_string(), "arn:aws:s3:us-*:123456789012:job/*");
+}
+
+#[test]
+fn test_pattern_same_error_as_resource_name() {
+    for input in &[
+        "",
+        "foo:bar",
+        "arn:aws:s3",
+        "arn:nowhere:s3:::my-bucket",
+        "arn:aws:nope:::my-bucket",
+        "arn:aws:sqs:us-east-1:123456789012:",
+    ] {
+        assert_eq!(
+            ArnPattern::from_str(input).err(),
+            ResourceName::from_str(input).err(),
+            "{:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_pattern_from_resource_name_pattern() {
+    let pattern = ResourceNamePattern::from_str("arn:aws:s3:::my-bucket/*").unwrap();
+    let arn = ResourceName::from_str("arn:aws:s3:::my-bucket/logs").unwrap();
+    assert!(ArnPattern::from(&pattern).matches(&arn));
+}
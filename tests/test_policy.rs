@@ -0,0 +1,36 @@
+#![cfg(feature = "serde")]
+
+use aws_arn::{from_policy_resource_json, to_policy_resource_json, ArnError, ResourceName};
+use serde_json::json;
+use std::str::FromStr;
+
+#[test]
+fn test_single_arn_to_policy_resource() {
+    let arns = vec![ResourceName::from_str("arn:aws:s3:::my-bucket/*").unwrap()];
+    let value = to_policy_resource_json(&arns);
+    assert_eq!(value, json!("arn:aws:s3:::my-bucket/*"));
+    assert_eq!(from_policy_resource_json(&value).unwrap(), arns);
+}
+
+#[test]
+fn test_many_arns_to_policy_resource() {
+    let arns = vec![
+        ResourceName::from_str("arn:aws:s3:::my-bucket").unwrap(),
+        ResourceName::from_str("arn:aws:s3:::my-bucket/*").unwrap(),
+    ];
+    let value = to_policy_resource_json(&arns);
+    assert_eq!(
+        value,
+        json!(["arn:aws:s3:::my-bucket", "arn:aws:s3:::my-bucket/*"])
+    );
+    assert_eq!(from_policy_resource_json(&value).unwrap(), arns);
+}
+
+#[test]
+fn test_invalid_policy_resource() {
+    assert_eq!(
+        from_policy_resource_json(&json!(["arn:aws:s3:::my-bucket", 42])),
+        Err(ArnError::InvalidPolicyResource("42".to_string()))
+    );
+    assert!(from_policy_resource_json(&json!("not-an-arn")).is_err());
+}
@@ -0,0 +1,60 @@
+use aws_arn::{Continent, Region};
+use rstest::rstest;
+use std::str::FromStr;
+
+#[rstest]
+#[case::lower("us-west-2", Region::UsWest2)]
+#[case::upper("US-WEST-2", Region::UsWest2)]
+#[case::mixed("Eu-Central-1", Region::EuCentral1)]
+#[case::aws_global("aws-global", Region::AwsGlobal)]
+#[case::us_gov_global("us-gov-global", Region::UsGovGlobal)]
+fn test_region_from_str_ignore_case(#[case] input: &str, #[case] expected: Region) {
+    let region = Region::from_str(input).unwrap();
+    assert_eq!(region, expected);
+    assert_eq!(region.to_string(), input.to_ascii_lowercase());
+}
+
+#[test]
+fn test_region_from_str_invalid() {
+    assert!(Region::from_str("us-nowhere-1").is_err());
+}
+
+// `Region::long_name` and `Region::continent` match without a wildcard arm, so adding a region
+// variant without this metadata is a compile error.
+#[rstest]
+#[case(Region::UsEast1, "US East (N. Virginia)", Continent::NorthAmerica)]
+#[case(Region::EuWest1, "Europe (Ireland)", Continent::Europe)]
+#[case(Region::ApSoutheast2, "Asia Pacific (Sydney)", Continent::Oceania)]
+#[case(Region::MeSouth1, "Middle East (Bahrain)", Continent::MiddleEast)]
+#[case(Region::SaEast1, "South America (São Paulo)", Continent::SouthAmerica)]
+#[case(Region::AwsGlobal, "AWS Global", Continent::Global)]
+fn test_region_metadata(
+    #[case] region: Region,
+    #[case] long_name: &str,
+    #[case] continent: Continent,
+) {
+    assert_eq!(region.long_name(), long_name);
+    assert_eq!(region.continent(), continent);
+}
+
+#[test]
+fn test_region_is_global() {
+    assert!(Region::AwsGlobal.is_global());
+    assert!(Region::UsGovGlobal.is_global());
+    assert!(!Region::UsEast1.is_global());
+}
+
+#[rstest]
+#[case(Region::AfSouth1, true)]
+#[case(Region::ApEast1, true)]
+#[case(Region::EuSouth1, true)]
+#[case(Region::MeSouth1, true)]
+#[case(Region::UsEast1, false)]
+#[case(Region::EuWest1, false)]
+#[case(Region::ApNortheast3, false)]
+#[case(Region::CnNorth1, false)]
+#[case(Region::UsGovWest1, false)]
+#[case(Region::AwsGlobal, false)]
+fn test_region_is_opt_in(#[case] region: Region, #[case] expected: bool) {
+    assert_eq!(region.is_opt_in(), expected);
+}
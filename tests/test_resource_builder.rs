@@ -0,0 +1,147 @@
+#![cfg(feature = "builders")]
+
+use aws_arn::builder::{ResourceBuilder, Separator};
+use aws_arn::{ArnError, Identifier, IdentifierLike, ResourceIdentifier};
+
+fn ids(values: &[&str]) -> Vec<Identifier> {
+    values
+        .iter()
+        .map(|s| Identifier::new_unchecked(s))
+        .collect()
+}
+
+#[test]
+fn test_resource_builder_from_ids() {
+    let resource =
+        ResourceBuilder::from_ids(&ids(&["user", "division_abc", "Bob"])).build_resource_path();
+    assert_eq!(resource.to_string(), "user/division_abc/Bob");
+}
+
+#[test]
+fn test_resource_builder_collect_path() {
+    let resource = ids(&["user", "Bob"])
+        .into_iter()
+        .collect::<ResourceBuilder>()
+        .build_resource_path();
+    assert_eq!(resource.to_string(), "user/Bob");
+}
+
+#[test]
+fn test_resource_builder_collect_qualified() {
+    let mut builder = ids(&["layer", "my-layer"])
+        .into_iter()
+        .map(ResourceIdentifier::from)
+        .collect::<ResourceBuilder>();
+    builder.extend(vec![ResourceIdentifier::new_unchecked("3")]);
+    assert_eq!(builder.build_qualified_id().to_string(), "layer:my-layer:3");
+}
+
+#[test]
+fn test_resource_builder_build_mixed() {
+    let resource = ResourceBuilder::from_ids(&ids(&["table", "Name", "stream", "2024", "latest"]))
+        .build_mixed(&[
+            Separator::Path,
+            Separator::Path,
+            Separator::Path,
+            Separator::Qualifier,
+        ])
+        .unwrap();
+    assert_eq!(resource.to_string(), "table/Name/stream/2024:latest");
+}
+
+#[test]
+fn test_resource_builder_build_mixed_separator_count() {
+    let builder = ResourceBuilder::from_ids(&ids(&["function", "my-function", "alias", "live"]));
+    assert_eq!(
+        builder.build_mixed(&[Separator::Qualifier]),
+        Err(ArnError::SeparatorCountMismatch {
+            expected: 3,
+            actual: 1
+        })
+    );
+    assert_eq!(
+        builder.build_mixed(&[Separator::Qualifier; 4]),
+        Err(ArnError::SeparatorCountMismatch {
+            expected: 3,
+            actual: 4
+        })
+    );
+}
+
+#[test]
+fn test_resource_builder_build_mixed_empty() {
+    assert_eq!(
+        ResourceBuilder::default().build_mixed(&[]),
+        Err(ArnError::InvalidResource(String::new()))
+    );
+}
+
+#[test]
+fn test_resource_builder_owned_chain() {
+    let resource = ResourceBuilder::typed(Identifier::new_unchecked("function"))
+        .with_resource_name(Identifier::new_unchecked("my-function"))
+        .with_version(3)
+        .build_qualified_id();
+    assert_eq!(resource.to_string(), "function:my-function:3");
+}
+
+#[test]
+fn test_resource_builder_owned_chain_stored() {
+    let builder = ResourceBuilder::typed(Identifier::new_unchecked("table"))
+        .with_resource_name(Identifier::new_unchecked("Name"))
+        .with_type_name(Identifier::new_unchecked("stream"))
+        .with_sub_resource_name(Identifier::new_unchecked("2024"));
+    assert_eq!(
+        builder.build_resource_path().to_string(),
+        "table/Name/stream/2024"
+    );
+    assert_eq!(
+        builder.build_qualified_id().to_string(),
+        "table:Name:stream:2024"
+    );
+}
+
+#[test]
+fn test_resource_builder_from_qualified_str() {
+    let builder = ResourceBuilder::from_qualified_str("layer:my-layer:3").unwrap();
+    assert_eq!(builder.build_qualified_id().to_string(), "layer:my-layer:3");
+    assert_eq!(
+        builder.build_resource_path().to_string(),
+        "layer/my-layer/3"
+    );
+}
+
+#[test]
+fn test_resource_builder_from_path_str() {
+    let builder = ResourceBuilder::from_path_str("user/division_abc/Bob").unwrap();
+    assert_eq!(
+        builder.build_resource_path().to_string(),
+        "user/division_abc/Bob"
+    );
+    assert_eq!(
+        builder.build_qualified_id().to_string(),
+        "user:division_abc:Bob"
+    );
+}
+
+#[test]
+fn test_resource_builder_from_absolute_path_str() {
+    let builder = ResourceBuilder::from_path_str("/restapis/a1b2c3").unwrap();
+    let resource = builder.build_resource_path();
+    assert_eq!(resource.to_string(), "/restapis/a1b2c3");
+    assert!(resource.is_absolute_path());
+    assert_eq!(
+        resource.path_segments().collect::<Vec<_>>(),
+        vec!["restapis", "a1b2c3"]
+    );
+}
+
+#[test]
+fn test_resource_builder_from_str_invalid() {
+    assert!(ResourceBuilder::from_qualified_str("").is_err());
+    assert!(ResourceBuilder::from_qualified_str("layer::3").is_err());
+    assert!(ResourceBuilder::from_path_str("user//Bob").is_err());
+    assert!(ResourceBuilder::from_path_str("user/Bob\n").is_err());
+    assert!(ResourceBuilder::from_path_str("/").is_err());
+    assert!(ResourceBuilder::from_path_str("//user/Bob").is_err());
+}
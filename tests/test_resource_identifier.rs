@@ -1,7 +1,9 @@
-use aws_arn::{IdentifierLike, ResourceIdentifier};
+use aws_arn::{
+    ArnError, Identifier, IdentifierLike, ResourceIdentifier, ResourceName, ResourceShape,
+};
 use proptest::prelude::*;
 use std::ops::Deref;
-use std::{collections::HashMap, iter::FromIterator, str::FromStr};
+use std::{collections::HashMap, convert::TryFrom, iter::FromIterator, str::FromStr};
 
 // ------------------------------------------------------------------------------------------------
 // API Tests
@@ -66,20 +68,113 @@ fn test_resource_identifier_is_valid_variable() {
 fn test_resource_identifier_valid_replacement() {
     let id = ResourceIdentifier::new_unchecked("${greeting} ${name}!");
     let replacements: HashMap<String, String> =
-        HashMap::from_iter(vec![("name".to_string(), "Simon".to_string())].into_iter());
+        HashMap::from_iter(vec![("name".to_string(), "Simon".to_string())]);
     let new_id = id.replace_variables(&replacements).unwrap();
     assert_eq!(new_id.deref(), "${greeting} Simon!");
 }
 
+#[test]
+fn test_resource_identifier_replacement_logged() {
+    let id = ResourceIdentifier::new_unchecked("${greeting} ${name}!");
+    let replacements: HashMap<String, String> =
+        HashMap::from_iter(vec![("name".to_string(), "Simon".to_string())]);
+    let (new_id, substitutions) = id.replace_variables_logged(&replacements).unwrap();
+    assert_eq!(new_id.deref(), "${greeting} Simon!");
+    assert_eq!(
+        substitutions,
+        vec![
+            ("greeting".to_string(), None),
+            ("name".to_string(), Some("Simon".to_string())),
+        ]
+    );
+
+    let id = ResourceIdentifier::new_unchecked("user/Bob");
+    let (new_id, substitutions) = id.replace_variables_logged(&replacements).unwrap();
+    assert_eq!(new_id, id);
+    assert!(substitutions.is_empty());
+}
+
+#[test]
+fn test_resource_identifier_no_variables_unchanged() {
+    let replacements: HashMap<String, String> =
+        HashMap::from_iter(vec![("name".to_string(), "Simon".to_string())]);
+
+    let id = ResourceIdentifier::new_unchecked("user/Bob");
+    assert_eq!(id.replace_variables(&replacements).unwrap(), id);
+
+    // Without variables the result is still validated.
+    let id = ResourceIdentifier::new_unchecked("bad\u{7}");
+    assert_eq!(
+        id.replace_variables(&replacements),
+        Err(ArnError::InvalidResourceCharacter('\u{7}'))
+    );
+}
+
 #[test]
 fn test_resource_identifier_invalid_replacement() {
     let id = ResourceIdentifier::new_unchecked("${greeting} ${name}!");
     let replacements: HashMap<String, String> =
-        HashMap::from_iter(vec![("name".to_string(), "bad\nвал".to_string())].into_iter());
+        HashMap::from_iter(vec![("name".to_string(), "bad\nвал".to_string())]);
     let new_id = id.replace_variables(&replacements);
     assert!(new_id.is_err());
 }
 
+#[test]
+fn test_resource_identifier_path_segments() {
+    let id = ResourceIdentifier::new_unchecked("a/b/c");
+    assert_eq!(id.path_segments().collect::<Vec<_>>(), vec!["a", "b", "c"]);
+    assert_eq!(id.qualifier_segments().collect::<Vec<_>>(), vec!["a/b/c"]);
+}
+
+#[test]
+fn test_resource_identifier_absolute_path() {
+    let arn =
+        ResourceName::from_str("arn:aws:apigateway:us-east-1::/restapis/abc123/resources/xyz")
+            .unwrap();
+    assert!(arn.resource.is_absolute_path());
+    assert_eq!(
+        arn.resource.path_segments().collect::<Vec<_>>(),
+        vec!["restapis", "abc123", "resources", "xyz"]
+    );
+    assert_eq!(
+        arn.resource.path_split(),
+        vec![
+            ResourceIdentifier::new_unchecked("restapis"),
+            ResourceIdentifier::new_unchecked("abc123"),
+            ResourceIdentifier::new_unchecked("resources"),
+            ResourceIdentifier::new_unchecked("xyz"),
+        ]
+    );
+    assert_eq!(arn.resource.last_segment(), "xyz");
+    assert!(!ResourceIdentifier::new_unchecked("a/b").is_absolute_path());
+}
+
+#[test]
+fn test_resource_identifier_qualifier_segments() {
+    let id = ResourceIdentifier::new_unchecked("a:b:c");
+    assert_eq!(
+        id.qualifier_segments().collect::<Vec<_>>(),
+        vec!["a", "b", "c"]
+    );
+    assert_eq!(id.path_segments().collect::<Vec<_>>(), vec!["a:b:c"]);
+}
+
+#[test]
+fn test_resource_identifier_last_segment() {
+    assert_eq!(
+        ResourceIdentifier::new_unchecked("my-bucket/logs/2024/file.txt").last_segment(),
+        "file.txt"
+    );
+    assert_eq!(
+        ResourceIdentifier::new_unchecked("plain").last_segment(),
+        "plain"
+    );
+    assert_eq!(
+        ResourceIdentifier::new_unchecked("trailing/").last_segment(),
+        ""
+    );
+}
+
 #[test]
 fn test_resource_identifier_is_not_valid() {
     assert!(!ResourceIdentifier::is_valid(""));
@@ -88,6 +183,102 @@ fn test_resource_identifier_is_not_valid() {
     assert!(!ResourceIdentifier::is_valid("\n"));
 }
 
+#[test]
+fn test_resource_identifier_shape() {
+    for (id, shape) in [
+        ("bucket", ResourceShape::Plain),
+        ("user/Bob", ResourceShape::Path),
+        ("layer:name:3", ResourceShape::Qualified),
+        ("type/name:qual", ResourceShape::Mixed),
+    ] {
+        assert_eq!(ResourceIdentifier::new_unchecked(id).shape(), shape);
+    }
+}
+
+#[test]
+fn test_resource_identifier_allows_unicode() {
+    let key = "photos/Zürich/été-2024.jpg";
+    assert!(ResourceIdentifier::is_valid(key));
+    assert_eq!(ResourceIdentifier::from_str(key).unwrap().to_string(), key);
+
+    let arn = ResourceName::from_str("arn:aws:s3:::my-bucket/photos/Zürich/été-2024.jpg").unwrap();
+    assert_eq!(
+        arn.resource.to_string(),
+        "my-bucket/photos/Zürich/été-2024.jpg"
+    );
+
+    // `Identifier` remains ASCII only.
+    assert!(!Identifier::is_valid("Zürich"));
+}
+
+#[test]
+fn test_resource_identifier_rejects_control_character() {
+    assert!(!ResourceIdentifier::is_valid("bell\u{7}"));
+    assert_eq!(
+        ResourceIdentifier::from_str("bell\u{7}"),
+        Err(ArnError::InvalidResourceCharacter('\u{7}'))
+    );
+    assert_eq!(
+        ResourceIdentifier::from_str("tab\there")
+            .unwrap_err()
+            .to_string(),
+        "resource contains the control character '\\t', which is not allowed"
+    );
+}
+
+#[test]
+fn test_resource_identifier_join_path() {
+    let bucket = ResourceIdentifier::new_unchecked("my-bucket");
+    let key = ResourceIdentifier::new_unchecked("photos/2024/cat.png");
+    let joined = bucket.join_path(&key).unwrap();
+    assert_eq!(joined.to_string(), "my-bucket/photos/2024/cat.png");
+    assert!(ResourceIdentifier::is_valid(&joined));
+    assert_eq!(joined, ResourceIdentifier::from_path(&[bucket, key]));
+}
+
+#[test]
+fn test_resource_identifier_join_qualifier() {
+    let base = ResourceIdentifier::new_unchecked("function:my-function");
+    let version = ResourceIdentifier::new_unchecked("3");
+    let joined = base.join_qualifier(&version).unwrap();
+    assert_eq!(joined.to_string(), "function:my-function:3");
+    assert_eq!(joined.shape(), ResourceShape::Qualified);
+}
+
+#[test]
+fn test_resource_identifier_join_mixed() {
+    let base = ResourceIdentifier::new_unchecked("log-group:/aws/lambda");
+    let joined = base
+        .join_path(&ResourceIdentifier::new_unchecked("my-function"))
+        .unwrap();
+    assert_eq!(joined.to_string(), "log-group:/aws/lambda/my-function");
+    assert_eq!(joined.shape(), ResourceShape::Mixed);
+}
+
+#[test]
+fn test_resource_identifier_join_invalid() {
+    let base = ResourceIdentifier::new_unchecked("my-bucket");
+    let segment = ResourceIdentifier::new_unchecked("bell\u{7}");
+    assert_eq!(
+        base.join_path(&segment),
+        Err(ArnError::InvalidResourceCharacter('\u{7}'))
+    );
+    assert_eq!(
+        base.join_qualifier(&segment),
+        Err(ArnError::InvalidResourceCharacter('\u{7}'))
+    );
+}
+
+#[test]
+fn test_resource_identifier_try_from_string() {
+    let owned = ResourceIdentifier::try_from("user/Bob".to_string()).unwrap();
+    assert_eq!(owned, ResourceIdentifier::from_str("user/Bob").unwrap());
+    assert_eq!(
+        ResourceIdentifier::try_from("bell\u{7}".to_string()),
+        Err(ArnError::InvalidResourceCharacter('\u{7}'))
+    );
+}
+
 // ------------------------------------------------------------------------------------------------
 // Automated Property Tests
 // ------------------------------------------------------------------------------------------------
@@ -103,4 +294,10 @@ proptest! {
         println!("valid_values {:?}", s);
         assert!(ResourceIdentifier::from_str(&s).is_ok());
     }
+
+    #[test]
+    fn proptest_resource_identifier_is_valid_matches_chars(s in any::<String>()) {
+        let expected = !s.is_empty() && !s.chars().any(char::is_control);
+        prop_assert_eq!(ResourceIdentifier::is_valid(&s), expected);
+    }
 }
@@ -0,0 +1,60 @@
+#![cfg(feature = "serde")]
+
+use aws_arn::{ArnError, ResourceName, ResourceRuleSet, Service};
+use std::str::FromStr;
+
+const RULES: &str = r#"{
+    "services": {
+        "lambda": {
+            "resource_types": ["function:", "layer:"],
+            "require_region": true,
+            "require_account": true
+        },
+        "s3": {}
+    }
+}"#;
+
+fn validate(arn: &str) -> Result<(), ArnError> {
+    let rules = ResourceRuleSet::from_json(RULES).unwrap();
+    ResourceName::from_str(arn)
+        .unwrap()
+        .validate_against(&rules)
+}
+
+#[test]
+fn test_load_rule_set() {
+    let rules = ResourceRuleSet::from_json(RULES).unwrap();
+    assert_eq!(rules.services.len(), 2);
+    let rule = rules.rule_for(&Service::Lambda).unwrap();
+    assert_eq!(rule.resource_types, vec!["function:", "layer:"]);
+    assert!(rule.require_region);
+    assert!(!rules.rule_for(&Service::S3).unwrap().require_region);
+}
+
+#[test]
+fn test_validate_against_conforming() {
+    assert_eq!(
+        validate("arn:aws:lambda:us-east-1:123456789012:function:my-function"),
+        Ok(())
+    );
+    assert_eq!(validate("arn:aws:s3:::my-bucket"), Ok(()));
+    assert_eq!(validate("arn:aws:iam::123456789012:user/Bob"), Ok(()));
+}
+
+#[test]
+fn test_validate_against_non_conforming() {
+    assert_eq!(
+        validate("arn:aws:lambda:us-east-1:123456789012:event-source-mapping:abc"),
+        Err(ArnError::InvalidResource(
+            "event-source-mapping:abc".to_string()
+        ))
+    );
+    assert_eq!(
+        validate("arn:aws:lambda::123456789012:function:my-function"),
+        Err(ArnError::MissingRegion)
+    );
+    assert_eq!(
+        validate("arn:aws:lambda:us-east-1::function:my-function"),
+        Err(ArnError::MissingAccountId)
+    );
+}
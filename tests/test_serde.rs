@@ -0,0 +1,264 @@
+#![cfg(feature = "serde")]
+
+use aws_arn::{
+    AccountId, AccountIdentifier, Identifier, Partition, Region, ResourceIdentifier, ResourceName,
+};
+use std::str::FromStr;
+
+#[test]
+fn test_deserialize_resource_name_struct() {
+    let json = r#"{
+        "partition": "aws",
+        "service": "lambda",
+        "region": "us-east-2",
+        "account_id": null,
+        "resource": "function:my-function"
+    }"#;
+    let arn: ResourceName = serde_json::from_str(json).unwrap();
+    assert_eq!(arn.region, Some(Region::UsEast2));
+    assert_eq!(
+        arn,
+        ResourceName::from_str("arn:aws:lambda:us-east-2::function:my-function").unwrap()
+    );
+}
+
+#[test]
+fn test_deserialize_resource_name_struct_bad_region() {
+    let json = r#"{
+        "partition": "aws",
+        "service": "lambda",
+        "region": "us-nowhere-1",
+        "account_id": null,
+        "resource": "function:my-function"
+    }"#;
+    let result = serde_json::from_str::<ResourceName>(json);
+    assert!(result.is_err());
+    let message = result.unwrap_err().to_string();
+    assert!(message.contains("region"), "{}", message);
+    assert!(message.contains("us-nowhere-1"), "{}", message);
+}
+
+#[test]
+fn test_deserialize_resource_name_struct_bad_service() {
+    let json = r#"{
+        "partition": "aws",
+        "service": "not-a-service",
+        "resource": "function:my-function"
+    }"#;
+    let message = serde_json::from_str::<ResourceName>(json)
+        .unwrap_err()
+        .to_string();
+    assert!(message.contains("`service`"), "{}", message);
+    assert!(message.contains("not-a-service"), "{}", message);
+}
+
+#[test]
+fn test_serialize_resource_name_as_string() {
+    let arn = ResourceName::from_str("arn:aws:lambda:us-east-2:123456789012:function:f").unwrap();
+    assert_eq!(
+        serde_json::to_string(&arn).unwrap(),
+        r#""arn:aws:lambda:us-east-2:123456789012:function:f""#
+    );
+}
+
+#[test]
+fn test_deserialize_resource_name_string() {
+    let json = r#""arn:aws:lambda:us-east-2:123456789012:function:f""#;
+    let arn: ResourceName = serde_json::from_str(json).unwrap();
+    assert_eq!(arn.region, Some(Region::UsEast2));
+    assert_eq!(serde_json::to_string(&arn).unwrap(), json);
+}
+
+#[test]
+fn test_deserialize_resource_name_struct_round_trip() {
+    let json = r#"{
+        "partition": "aws",
+        "service": "s3",
+        "resource": "my-bucket"
+    }"#;
+    let arn: ResourceName = serde_json::from_str(json).unwrap();
+    let serialized = serde_json::to_string(&arn).unwrap();
+    assert_eq!(serialized, r#""arn:aws:s3:::my-bucket""#);
+    assert_eq!(
+        serde_json::from_str::<ResourceName>(&serialized).unwrap(),
+        arn
+    );
+}
+
+#[test]
+fn test_deserialize_resource_name_bad_string() {
+    let message = serde_json::from_str::<ResourceName>(r#""arn:aws:s3""#)
+        .unwrap_err()
+        .to_string();
+    assert!(message.contains("component"), "{}", message);
+}
+
+#[test]
+fn test_deserialize_resource_name_wrong_type() {
+    let message = serde_json::from_str::<ResourceName>("42")
+        .unwrap_err()
+        .to_string();
+    assert!(message.contains("an ARN string or a map"), "{}", message);
+}
+
+#[test]
+fn test_deserialize_resource_name_legacy_sequence() {
+    let json = r#"["aws", "sqs", "us-east-1", "123456789012", "my-queue"]"#;
+    let arn: ResourceName = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:sqs:us-east-1:123456789012:my-queue"
+    );
+}
+
+#[test]
+fn test_resource_name_round_trip_not_human_readable() {
+    let arn = ResourceName::from_str("arn:aws:lambda:us-east-2:123456789012:function:f").unwrap();
+    let mut bytes = Vec::new();
+    ciborium::into_writer(&arn, &mut bytes).unwrap();
+    let decoded: ResourceName = ciborium::from_reader(bytes.as_slice()).unwrap();
+    assert_eq!(decoded, arn);
+}
+
+#[test]
+fn test_identifier_serializes_as_string() {
+    let id = Identifier::from_str("my-layer").unwrap();
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, r#""my-layer""#);
+    assert_eq!(serde_json::from_str::<Identifier>(&json).unwrap(), id);
+    assert!(serde_json::from_str::<Identifier>(r#""my layer""#).is_err());
+}
+
+#[test]
+fn test_resource_identifier_serializes_as_string() {
+    let id = ResourceIdentifier::from_str("user/Bob").unwrap();
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, r#""user/Bob""#);
+    assert_eq!(
+        serde_json::from_str::<ResourceIdentifier>(&json).unwrap(),
+        id
+    );
+    assert!(serde_json::from_str::<ResourceIdentifier>(r#""""#).is_err());
+}
+
+#[test]
+fn test_account_identifier_serializes_as_string() {
+    let account = AccountIdentifier::from_str("123456789012").unwrap();
+    let json = serde_json::to_string(&account).unwrap();
+    assert_eq!(json, r#""123456789012""#);
+    assert_eq!(
+        serde_json::from_str::<AccountIdentifier>(&json).unwrap(),
+        account
+    );
+    assert_eq!(
+        serde_json::to_string(&AccountId::from_str("123456789012").unwrap()).unwrap(),
+        json
+    );
+    assert!(serde_json::from_str::<AccountId>(r#""1234""#).is_err());
+}
+
+#[test]
+fn test_deserialize_resource_name_struct_with_account() {
+    let json = r#"{
+        "partition": "aws",
+        "service": "sqs",
+        "region": "us-east-1",
+        "account_id": "123456789012",
+        "resource": "my-queue"
+    }"#;
+    let arn: ResourceName = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:sqs:us-east-1:123456789012:my-queue"
+    );
+}
+
+#[test]
+fn test_deserialize_resource_name_struct_with_legacy_account() {
+    let json = r#"{
+        "partition": "aws",
+        "service": "sqs",
+        "region": "us-east-1",
+        "account_id": {"Account": "123456789012"},
+        "resource": "my-queue"
+    }"#;
+    let arn: ResourceName = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        arn.to_string(),
+        "arn:aws:sqs:us-east-1:123456789012:my-queue"
+    );
+
+    let json = r#"{
+        "partition": "aws",
+        "service": "iam",
+        "account_id": {"Service": "aws"},
+        "resource": "policy/ReadOnlyAccess"
+    }"#;
+    let arn: ResourceName = serde_json::from_str(json).unwrap();
+    assert_eq!(arn.to_string(), "arn:aws:iam::aws:policy/ReadOnlyAccess");
+}
+
+#[test]
+fn test_deserialize_resource_name_struct_bad_account_id() {
+    let json = r#"{
+        "partition": "aws",
+        "service": "sqs",
+        "region": "us-east-1",
+        "account_id": "1234 5678",
+        "resource": "my-queue"
+    }"#;
+    let message = serde_json::from_str::<ResourceName>(json)
+        .unwrap_err()
+        .to_string();
+    assert!(message.contains("`account_id`"), "{}", message);
+    assert!(message.contains("1234 5678"), "{}", message);
+}
+
+#[test]
+fn test_deserialize_resource_name_struct_without_account_id() {
+    let json = r#"{
+        "partition": "aws",
+        "service": "s3",
+        "resource": "my-bucket"
+    }"#;
+    let arn: ResourceName = serde_json::from_str(json).unwrap();
+    assert_eq!(arn.account_id, None);
+}
+
+#[test]
+fn test_region_serde_round_trip() {
+    let json = serde_json::to_string(&Region::UsGovWest1).unwrap();
+    assert_eq!(json, r#""us-gov-west-1""#);
+    assert_eq!(
+        serde_json::from_str::<Region>(&json).unwrap(),
+        Region::UsGovWest1
+    );
+    assert_eq!(
+        serde_json::from_str::<Partition>(r#""aws-cn""#).unwrap(),
+        Partition::AwsChina
+    );
+}
+
+#[test]
+fn test_region_deserialize_error_is_concise() {
+    let message = serde_json::from_str::<Region>(r#""us-nowhere-1""#)
+        .unwrap_err()
+        .to_string();
+    assert!(message.contains("us-nowhere-1"), "{}", message);
+    assert!(message.contains("not a valid region"), "{}", message);
+    assert!(!message.contains("eu-west-1"), "{}", message);
+    assert!(message.len() < 100, "{}", message);
+}
+
+#[test]
+fn test_partition_deserialize_error_is_concise() {
+    let message = serde_json::from_str::<Partition>(r#""aws-mars""#)
+        .unwrap_err()
+        .to_string();
+    assert!(
+        message.contains("aws-mars is not a valid partition"),
+        "{}",
+        message
+    );
+    assert!(message.len() < 100, "{}", message);
+}
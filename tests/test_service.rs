@@ -0,0 +1,109 @@
+use aws_arn::{Service, ServiceCategory};
+use rstest::rstest;
+use std::collections::HashSet;
+use std::str::FromStr;
+use strum::VariantArray;
+
+#[rstest]
+#[case::upper("S3", Service::S3, "s3")]
+#[case::lower("lambda", Service::Lambda, "lambda")]
+#[case::title("Lambda", Service::Lambda, "lambda")]
+#[case::hyphenated("EXECUTE-API", Service::ApiGatewayExecuteApi, "execute-api")]
+#[case::mixed("DynamoDB", Service::DynamoDb, "dynamodb")]
+fn test_service_from_str_ignore_case(
+    #[case] input: &str,
+    #[case] expected: Service,
+    #[case] display: &str,
+) {
+    let service = Service::from_str(input).unwrap();
+    assert_eq!(service, expected);
+    assert_eq!(service.to_string(), display);
+}
+
+#[test]
+fn test_service_from_str_invalid() {
+    assert!(Service::from_str("not-a-service").is_err());
+}
+
+#[rstest]
+#[case::same(Service::S3, "s3")]
+#[case::same_hyphenated(Service::ApiGatewayExecuteApi, "execute-api")]
+#[case::same_logs(Service::CloudWatchLogs, "logs")]
+#[case::same_states(Service::States, "states")]
+#[case::cloudwatch(Service::CloudWatch, "monitoring")]
+#[case::step_functions(Service::StepFunctions, "states")]
+#[case::ses(Service::SimpleEmail, "email")]
+#[case::management_api(Service::ApiGatewayManagementApi, "execute-api")]
+#[case::outposts(Service::S3Outposts, "s3-outposts")]
+fn test_service_endpoint_prefix(#[case] service: Service, #[case] expected: &str) {
+    assert_eq!(service.endpoint_prefix(), expected);
+}
+
+#[rstest]
+#[case::same("s3", Service::S3)]
+#[case::execute_api("execute-api", Service::ApiGatewayExecuteApi)]
+#[case::states("states", Service::States)]
+#[case::cloudwatch("monitoring", Service::CloudWatch)]
+#[case::elb("elasticloadbalancing", Service::ElasticLoadBalancing)]
+#[case::sso("portal.sso", Service::SingleSignOn)]
+fn test_service_from_endpoint_prefix(#[case] prefix: &str, #[case] expected: Service) {
+    assert_eq!(Service::from_endpoint_prefix(prefix), Some(expected));
+}
+
+#[test]
+fn test_service_from_endpoint_prefix_inverse() {
+    assert_eq!(Service::from_endpoint_prefix("not-a-service"), None);
+    for service in Service::VARIANTS {
+        let found = Service::from_endpoint_prefix(service.endpoint_prefix()).unwrap();
+        assert_eq!(found.endpoint_prefix(), service.endpoint_prefix());
+    }
+}
+
+#[test]
+fn test_service_as_str() {
+    assert_eq!(Service::S3.as_str(), "s3");
+    for service in [
+        Service::S3,
+        Service::S3Outposts,
+        Service::ApiGatewayExecuteApi,
+        Service::IdentityAccessManagement,
+    ] {
+        assert_eq!(service.as_str(), service.to_string());
+    }
+}
+
+// `Service::category` matches without a wildcard arm, so adding a service variant without
+// a category is a compile error.
+#[rstest]
+#[case(Service::S3, ServiceCategory::Storage)]
+#[case(Service::Lambda, ServiceCategory::Compute)]
+#[case(
+    Service::IdentityAccessManagement,
+    ServiceCategory::SecurityIdentityCompliance
+)]
+#[case(Service::DynamoDb, ServiceCategory::Database)]
+#[case(Service::Ec2ContainerService, ServiceCategory::Containers)]
+#[case(Service::SageMaker, ServiceCategory::MachineLearning)]
+fn test_service_category(#[case] service: Service, #[case] expected: ServiceCategory) {
+    assert_eq!(service.category(), expected);
+}
+
+#[test]
+fn test_service_id_round_trip() {
+    for service in Service::VARIANTS {
+        assert_eq!(Service::from_id(service.id()).as_ref(), Some(service));
+    }
+}
+
+#[test]
+fn test_service_ids_unique() {
+    let ids: HashSet<u16> = Service::VARIANTS.iter().map(Service::id).collect();
+    assert_eq!(ids.len(), Service::VARIANTS.len());
+    assert!(!ids.contains(&0));
+}
+
+#[test]
+fn test_service_ids_stable() {
+    assert_eq!(Service::AccessAnalyzer.id(), 1);
+    assert_eq!(Service::XRay.id(), 267);
+}
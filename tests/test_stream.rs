@@ -0,0 +1,101 @@
+use aws_arn::{validate_stream, OutputFormat, StreamSummary};
+use pretty_assertions::assert_eq;
+
+const INPUT: &str = "# a comment
+arn:aws:s3:::my-bucket
+
+not-an-arn
+arn:aws:iam::123456789012:user/Bob
+";
+
+#[test]
+fn test_validate_stream_text() {
+    let mut output = Vec::new();
+    let summary = validate_stream(INPUT.as_bytes(), &mut output, OutputFormat::Text).unwrap();
+    assert_eq!(
+        summary,
+        StreamSummary {
+            valid: 2,
+            invalid: 1
+        }
+    );
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "2\tok\tarn:aws:s3:::my-bucket
+4\terror\tprovided string has 1 components, must have at least 6
+5\tok\tarn:aws:iam::123456789012:user/Bob
+"
+    );
+}
+
+#[test]
+fn test_validate_stream_crlf() {
+    let input = INPUT.replace('\n', "\r\n");
+    let mut output = Vec::new();
+    let summary = validate_stream(input.as_bytes(), &mut output, OutputFormat::Text).unwrap();
+    assert_eq!(
+        summary,
+        StreamSummary {
+            valid: 2,
+            invalid: 1
+        }
+    );
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "2\tok\tarn:aws:s3:::my-bucket
+4\terror\tprovided string has 1 components, must have at least 6
+5\tok\tarn:aws:iam::123456789012:user/Bob
+"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_validate_stream_json_lines() {
+    let mut output = Vec::new();
+    let summary = validate_stream(INPUT.as_bytes(), &mut output, OutputFormat::JsonLines).unwrap();
+    assert_eq!(
+        summary,
+        StreamSummary {
+            valid: 2,
+            invalid: 1
+        }
+    );
+
+    let lines: Vec<serde_json::Value> = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(
+        lines[0],
+        serde_json::json!({"line": 2, "input": "arn:aws:s3:::my-bucket", "valid": true})
+    );
+    assert_eq!(lines[1]["line"], 4);
+    assert_eq!(lines[1]["valid"], false);
+    assert!(lines[1]["error"].is_string());
+}
+
+#[cfg(not(feature = "serde"))]
+#[test]
+fn test_validate_stream_json_lines_unsupported() {
+    let mut output = Vec::new();
+    let error =
+        validate_stream(INPUT.as_bytes(), &mut output, OutputFormat::JsonLines).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::Unsupported);
+    assert!(output.is_empty());
+}
+
+#[cfg(not(feature = "serde"))]
+#[test]
+fn test_validate_stream_json_lines_unsupported_without_arns() {
+    let mut output = Vec::new();
+    let error = validate_stream(
+        "# only a comment\n".as_bytes(),
+        &mut output,
+        OutputFormat::JsonLines,
+    )
+    .unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::Unsupported);
+}